@@ -0,0 +1,65 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use cyrla::ConverterBuilder;
+
+const LATIN_TEXT: &str = "Konjugacija u genetici je sparivanje homolognih hromozoma u mejozi. \
+    Preko noći proljeće nam dođe, pored Une uz vrbike lazi, bijele trešnje, blistavi đerdani. \
+    Džezvu za kafu sam uzela za Đurđevdan, a njen odjek se čuo nadaleko. ";
+
+const CYRILLIC_TEXT: &str = "Конјугација у генетици је спаривање хомологних хромозома у мејози. \
+    Преко ноћи прољеће нам дође, поред Уне уз врбике лази, бијеле трешње, блистави ђердани. \
+    Џезву за кафу сам узела за Ђурђевдан, а њен одјек се чуо надалеко. ";
+
+fn lat_to_cyr_text(c: &mut Criterion) {
+    let converter = ConverterBuilder::new()
+        .enable_bald_latin()
+        .enable_ijekavian_inclusion()
+        .build();
+    let mut group = c.benchmark_group("lat_to_cyr/text");
+
+    for repetitions in [1, 100, 10_000] {
+        let input = LATIN_TEXT.repeat(repetitions);
+
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(input.len()), &input, |b, input| {
+            b.iter(|| converter.lat_to_cyr(black_box(input)))
+        });
+    }
+
+    group.finish();
+}
+
+/// Single word full of digraphs, which used to be quadratic in the length of the word
+fn lat_to_cyr_long_word(c: &mut Criterion) {
+    let converter = ConverterBuilder::new().enable_bald_latin().build();
+    let mut group = c.benchmark_group("lat_to_cyr/long_word");
+
+    for repetitions in [10, 100, 1_000] {
+        let input = "konjdžadjnja".repeat(repetitions);
+
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(input.len()), &input, |b, input| {
+            b.iter(|| converter.lat_to_cyr(black_box(input)))
+        });
+    }
+
+    group.finish();
+}
+
+fn cyr_to_lat_text(c: &mut Criterion) {
+    let converter = ConverterBuilder::new().build();
+    let mut group = c.benchmark_group("cyr_to_lat/text");
+
+    for repetitions in [1, 100, 10_000] {
+        let input = CYRILLIC_TEXT.repeat(repetitions);
+
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(input.len()), &input, |b, input| {
+            b.iter(|| converter.cyr_to_lat(black_box(input)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, lat_to_cyr_text, lat_to_cyr_long_word, cyr_to_lat_text);
+criterion_main!(benches);
@@ -98,3 +98,48 @@ pub const IJEKAVIAN_PREFIXES: [&str; 51] = [
     "djelov",
     "prolj",
 ];
+
+pub const MACEDONIAN_LITERAL_PREFIXES: [&str; 9] = [
+    "anjon",
+    "injek",
+    "konjug",
+    "konjunk",
+    "nadživ",
+    "nadz",
+    "odz",
+    "podz",
+    "predz",
+];
+
+pub const ENGLISH_DIGRAPH_STEMS: [&str; 30] = [
+    "bezh",
+    "beshrabr",
+    "ishitr",
+    "ishlap",
+    "ishran",
+    "izh",
+    "razh",
+    "shlad",
+    "shlađ",
+    "shem",
+    "shod",
+    "shvać",
+    "shvat",
+    "ushić",
+    "ushit",
+    "tjed",
+    "tjem",
+    "tjera",
+    "tjesk",
+    "tjesn",
+    "htje",
+    "hotje",
+    "letje",
+    "istje",
+    "natje",
+    "otje",
+    "utje",
+    "zatje",
+    "vrtje",
+    "stjeg",
+];
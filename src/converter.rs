@@ -1,24 +1,186 @@
-use trie_rs::Trie;
+use automaton::CharTrie;
+use dialect::DialectWords;
+use restoration::Restorer;
+use scheme::{CyrillicLetter, LatinLetter};
 
+mod automaton;
+#[allow(clippy::module_inception)]
 mod converter;
 mod converter_builder;
+mod dialect;
+mod historical;
+mod icao;
+mod names;
+mod restoration;
+mod scheme;
+mod stream;
 
-pub struct ConverterConfig<'a> {
+/// Direction of the conversion between scripts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    LatinToCyrillic,
+    CyrillicToLatin,
+}
+
+/// Case of the latin digraph (`lj`, `nj` or `dž`) converted from the uppercase cyrillic letter
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DigraphCase {
+    /// Only the first letter is uppercase (`Lj`)
+    Title,
+    /// Both letters are uppercase (`LJ`)
+    Upper,
+}
+
+/// What to do with a word whose diacritics can be restored in several ways (`sto` as `što` or
+/// `sto`)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RestorationFallback {
+    /// Keep the word as it's written
+    #[default]
+    Keep,
+    /// Use the most common word (`sto` as `što`)
+    MostCommon,
+}
+
+/// What to do with latin letters which aren't in the alphabet of the scheme (`q`, `w`, `x` and
+/// `y` in Serbian)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ForeignLetterPolicy {
+    /// Keep the letters as they are, converting the rest of the word (`taxi` to `таxи`)
+    #[default]
+    Keep,
+    /// Transcribe the letters (`w` to `в`, `x` to `кс`, `q` to `к`, `y` to `ј` next to a vowel
+    /// and to `и` otherwise)
+    Transcribe,
+    /// Keep the whole word unconverted (`Wi-Fi`)
+    SkipWord,
+    /// Like `SkipWord`, but `Converter::try_lat_to_cyr` fails with the list of such words
+    Fail,
+}
+
+/// Rule by which the foreign word detection recognises a latin word as foreign, so it's kept
+/// unconverted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ForeignWordRule {
+    /// Word has latin letters outside of the alphabet of the scheme (`Wi-Fi`, `Müller`)
+    ForeignLetters,
+    /// Word has an uppercase letter right after a lowercase one (`iPhone`, `JavaScript`)
+    CamelCase,
+    /// Word has both digits and letters (`OAuth2`, `mp3`)
+    DigitsWithLetters,
+    /// Word has a sequence of letters which doesn't exist in the language of the scheme (`th`
+    /// or `ck` in Serbian)
+    ImpossibleSequences,
+    /// Word is written in uppercase latin letters and has letters outside of the alphabet or
+    /// sequences of letters which don't exist in the language of the scheme (`FAQ`, `PHP`), or
+    /// was added by `ConverterBuilder::extend_foreign_acronyms` (`README`, `TODO`)
+    Acronyms,
+}
+
+/// Language of foreign names, whose rules are used for transcribing them to cyrillic
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SourceLanguage {
+    English,
+    German,
+    French,
+    Italian,
+}
+
+/// Pronunciation of the old letter yat (`ѣ`) in Serbian words
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dialect {
+    /// Yat as `e` (`mleko`, `dete`), used in Serbia
+    Ekavian,
+    /// Yat as `ije` or `je` (`mlijeko`, `dijete`), used in Bosnia and Herzegovina, Montenegro
+    /// and Croatia
+    Ijekavian,
+}
+
+/// Alphabets and rules used for the conversion between scripts
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Scheme {
+    /// Serbian cyrillic alphabet and Gaj's latin alphabet
+    #[default]
+    Serbian,
+    /// Macedonian cyrillic alphabet and its latin alphabet (`ѓ` as `ǵ`, `ѕ` as `dz`, `ќ` as `ḱ`)
+    Macedonian,
+    /// Montenegrin cyrillic and latin alphabets, which add `С́` (`Ś`) and `З́` (`Ź`) to the
+    /// Serbian ones. Ijekavian words are always included in the dictionary.
+    Montenegrin,
+    /// Russian transliteration by ISO 9:1995, which is reversible (`щ` as `ŝ`, `я` as `â`)
+    RussianIso9,
+    /// Russian transliteration by GOST 7.79-2000 system B, which uses only ASCII characters
+    /// (`щ` as `shh`, `ы` as ``y` ``)
+    RussianGost779B,
+    /// Russian romanization by BGN/PCGN (`щ` as `shch`, `е` as `ye` at the start of the word)
+    RussianBgnPcgn,
+    /// Official Ukrainian transliteration of 2010, used in passports (`я` as `ya` at the start of
+    /// the word and `ia` elsewhere, `зг` as `zgh`)
+    Ukrainian,
+    /// Bulgarian Streamlined System (`щ` as `sht`, `ъ` as `a`, `-ия` at the end of the word as
+    /// `-ia`)
+    Bulgarian,
+    /// Serbian alphabets with one-to-one conversion, where `љ`, `њ` and `џ` are written as `l̂`,
+    /// `n̂` and `d̂`, and latin characters of the input are escaped with `\` when converting to
+    /// latin, so converting the result back to cyrillic always gives the original text
+    SerbianReversible,
+}
+
+#[derive(Clone, Debug)]
+pub struct ConverterConfig {
     dj_conversion_enabled: bool,
     dz_conversion_enabled: bool,
-    ignored_latin_words: Option<&'a Vec<&'a str>>,
+    english_digraphs_enabled: bool,
+    foreign_letter_policy: ForeignLetterPolicy,
+    foreign_word_rules: Vec<ForeignWordRule>,
+    foreign_acronyms: Vec<String>,
+    language_confidence_threshold: Option<f64>,
+    historical_yat: Option<Dialect>,
+    single_letter_digraph_case: DigraphCase,
 }
 
-pub struct Converter<'a> {
-    dictionary: Trie<u8>,
-    ignored_latin_words: Option<Trie<u8>>,
-    config: ConverterConfig<'a>,
+/// Converter between latin and cyrillic script created by the `ConverterBuilder`.
+///
+/// Converter owns all of its data, so it can be cloned, shared between threads and stored in a
+/// `static`.
+#[derive(Clone, Debug)]
+pub struct Converter {
+    dictionary: CharTrie<()>,
+    english_digraph_stems: Option<CharTrie<()>>,
+    latin_letters: CharTrie<LatinLetter>,
+    cyrillic_letters: CharTrie<CyrillicLetter>,
+    escaped_chars: Vec<char>,
+    ignored_latin_words: Option<CharTrie<()>>,
+    restorer: Option<Restorer>,
+    names: Option<CharTrie<String>>,
+    dialect_words: Option<DialectWords>,
+    scheme: Scheme,
+    config: ConverterConfig,
 }
 
-pub struct ConverterBuilder<'a> {
-    dictionary: Vec<&'a str>,
-    ignored_latin_words: Vec<&'a str>,
+/// Builder of the `Converter`. Building doesn't change the builder, so it can be cloned and used
+/// for building several converters.
+#[derive(Clone, Debug)]
+pub struct ConverterBuilder {
+    scheme: Scheme,
+    dictionary: Vec<String>,
+    ignored_latin_words: Vec<String>,
     dj_conversion_enabled: bool,
     dz_conversion_enabled: bool,
+    english_digraphs_enabled: bool,
+    foreign_letter_policy: ForeignLetterPolicy,
+    foreign_word_rules: Vec<ForeignWordRule>,
+    foreign_acronyms: Vec<String>,
+    language_identification_enabled: bool,
+    language_confidence_threshold: f64,
     ijekavian_inclusion_enabled: bool,
+    single_letter_digraph_case: DigraphCase,
+    diacritic_restoration_enabled: bool,
+    restoration_fallback: RestorationFallback,
+    restoration_words: Vec<String>,
+    name_transcription_enabled: bool,
+    name_transcriptions: Vec<(String, String)>,
+    foreign_names: Vec<(SourceLanguage, String)>,
+    dialect_words: Vec<(String, String)>,
+    historical_yat: Option<Dialect>,
 }
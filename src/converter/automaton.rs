@@ -0,0 +1,191 @@
+/// Trie over characters which is compiled once by the builder and then walked once per word.
+///
+/// Every node keeps its outgoing edges sorted by character, so a transition is a binary search
+/// and walking a word never allocates. Transitions from the root for characters up to the end of
+/// the Cyrillic block are additionally kept in a lookup table, since almost every character of
+/// the converted text starts a new walk.
+#[derive(Clone, Debug)]
+pub(crate) struct CharTrie<T> {
+    nodes: Vec<Node<T>>,
+    root: Vec<usize>,
+}
+
+/// Characters below this code point have their transition from the root in the lookup table
+const ROOT_TABLE_SIZE: usize = 0x500;
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    edges: Vec<(char, usize)>,
+    value: Option<T>,
+}
+
+impl<T> Default for Node<T> {
+    fn default() -> Self {
+        Node {
+            edges: vec![],
+            value: None,
+        }
+    }
+}
+
+impl<T> Default for CharTrie<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CharTrie<T> {
+    pub(crate) fn new() -> CharTrie<T> {
+        CharTrie {
+            nodes: vec![Node::default()],
+            root: vec![0; ROOT_TABLE_SIZE],
+        }
+    }
+
+    /// Inserts the key, replacing the value if the key was already present
+    pub(crate) fn insert<I: IntoIterator<Item = char>>(&mut self, key: I, value: T) {
+        *self.value_mut(key) = Some(value);
+    }
+
+    /// Returns the slot of the value stored under the key, adding the key if it isn't present
+    pub(crate) fn value_mut<I: IntoIterator<Item = char>>(&mut self, key: I) -> &mut Option<T> {
+        let mut node = 0;
+
+        for c in key {
+            node = match self.nodes[node].edges.binary_search_by_key(&c, |&(edge, _)| edge) {
+                Ok(index) => self.nodes[node].edges[index].1,
+                Err(index) => {
+                    let next = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[node].edges.insert(index, (c, next));
+
+                    if node == 0 && (c as usize) < ROOT_TABLE_SIZE {
+                        self.root[c as usize] = next;
+                    }
+
+                    next
+                }
+            };
+        }
+
+        &mut self.nodes[node].value
+    }
+
+    /// Returns `true` if the key is a prefix of a key in the trie, or a key itself
+    pub(crate) fn contains_path<I: IntoIterator<Item = char>>(&self, key: I) -> bool {
+        let mut node = 0;
+
+        for c in key {
+            node = match self.step(node, c) {
+                Some(next) => next,
+                None => return false,
+            };
+        }
+
+        true
+    }
+
+    /// Returns `true` if some key starts with the given character
+    pub(crate) fn starts_key(&self, c: char) -> bool {
+        self.step(0, c).is_some()
+    }
+
+    fn step(&self, node: usize, c: char) -> Option<usize> {
+        if node == 0 && (c as usize) < ROOT_TABLE_SIZE {
+            return Some(self.root[c as usize]).filter(|&next| next != 0);
+        }
+
+        let edges = &self.nodes[node].edges;
+
+        edges
+            .binary_search_by_key(&c, |&(edge, _)| edge)
+            .ok()
+            .map(|index| edges[index].1)
+    }
+
+    /// Returns value stored under exactly the given key
+    pub(crate) fn get<I: IntoIterator<Item = char>>(&self, key: I) -> Option<&T> {
+        let mut node = 0;
+
+        for c in key {
+            node = self.step(node, c)?;
+        }
+
+        self.nodes[node].value.as_ref()
+    }
+
+    /// Returns `true` if the exact key is present in the trie
+    pub(crate) fn exact_match(&self, key: &str) -> bool {
+        self.get(key.chars()).is_some()
+    }
+
+    /// Walks the trie along `chars` and returns the length (in characters) and the value of the
+    /// longest key which is a prefix of `chars` and whose value is accepted by `accept`
+    pub(crate) fn longest_match_by<I, F>(&self, chars: I, mut accept: F) -> Option<(usize, &T)>
+    where
+        I: IntoIterator<Item = char>,
+        F: FnMut(usize, &T) -> bool,
+    {
+        let mut node = 0;
+        let mut longest = None;
+
+        for (i, c) in chars.into_iter().enumerate() {
+            node = match self.step(node, c) {
+                Some(next) => next,
+                None => break,
+            };
+
+            if let Some(value) = &self.nodes[node].value {
+                if accept(i + 1, value) {
+                    longest = Some((i + 1, value));
+                }
+            }
+
+            if self.nodes[node].edges.is_empty() {
+                break;
+            }
+        }
+
+        longest
+    }
+
+    /// Returns length in bytes and the value of the longest key which is a prefix of `text` and
+    /// whose value is accepted by `accept`, which receives the length of the key in bytes
+    pub(crate) fn longest_match_in<'a, F>(
+        &'a self,
+        text: &str,
+        mut accept: F,
+    ) -> Option<(usize, &'a T)>
+    where
+        F: FnMut(usize, &'a T) -> bool,
+    {
+        let mut node = 0;
+        let mut longest = None;
+
+        for (i, c) in text.char_indices() {
+            node = match self.step(node, c) {
+                Some(next) => next,
+                None => break,
+            };
+
+            if let Some(value) = &self.nodes[node].value {
+                let len = i + c.len_utf8();
+
+                if accept(len, value) {
+                    longest = Some((len, value));
+                }
+            }
+
+            if self.nodes[node].edges.is_empty() {
+                break;
+            }
+        }
+
+        longest
+    }
+
+    /// Returns length (in characters) of the longest key which is a prefix of `chars`
+    pub(crate) fn longest_prefix_len<I: IntoIterator<Item = char>>(&self, chars: I) -> Option<usize> {
+        self.longest_match_by(chars, |_, _| true).map(|(len, _)| len)
+    }
+}
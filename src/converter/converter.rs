@@ -1,403 +1,661 @@
-use std::str;
-use trie_rs::{Trie, TrieBuilder};
-use super::{Converter, ConverterConfig};
-
-trait CharSlice {
-    fn char_slice(&self, begin: usize, end: usize) -> &Self;
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::automaton::CharTrie;
+use super::dialect::DialectWords;
+use super::restoration::Restorer;
+use super::scheme::{LetterCase, LetterKind};
+use super::{
+    Converter, ConverterConfig, DigraphCase, Direction, ForeignLetterPolicy, ForeignWordRule, Scheme,
+};
+use crate::constants::ENGLISH_DIGRAPH_STEMS;
+use crate::language::{identify_language, Language};
+use crate::report::{ConversionReport, ForeignLettersError, Issue, IssueKind};
+use crate::script::{detect_script, Script};
+use crate::tokenizer::{tokenize, Token, TokenKind};
+
+/// Characters which join parts of hyphenated compounds
+const HYPHENS: [char; 3] = ['-', '\u{2010}', '\u{2011}'];
+
+/// Part of the latin input, as it's passed to the conversion
+enum LatinPart<'a> {
+    /// Separator or a word which is kept as it is
+    Kept(&'a str),
+    /// Part of a hyphenated word, with restored diacritics
+    Word(Cow<'a, str>),
+    /// Foreign name with its cyrillic transcription
+    Name(&'a str, String),
 }
 
-impl CharSlice for str {
-    fn char_slice(&self, begin: usize, end: usize) -> &str {
-        let mut begin_byte = self.len();
-        let mut end_byte = self.len();
-
-        let end_position = if end == 0 { 1 } else { end };
+/// Compiles the words into a trie. A word which is a prefix of an already added word is skipped
+/// (`nadživ` after `nadžive`).
+fn compile_words(words: &[&str]) -> CharTrie<()> {
+    let mut trie = CharTrie::new();
 
-        for (i, (byte_index, _)) in self.char_indices().enumerate() {
-            if i == begin {
-                begin_byte = byte_index;
-            } else if i == end_position {
-                end_byte = byte_index;
-                break;
-            }
+    for word in words {
+        if !trie.contains_path(word.chars()) {
+            trie.insert(word.chars(), ());
         }
-
-        unsafe { self.get_unchecked(begin_byte..end_byte) }
     }
-}
 
-fn lat_letter_to_cyr(letter: char) -> Option<char> {
-    match letter {
-        'A' => Some('А'),
-        'B' => Some('Б'),
-        'V' => Some('В'),
-        'G' => Some('Г'),
-        'D' => Some('Д'),
-        'Đ' => Some('Ђ'),
-        'E' => Some('Е'),
-        'Ž' => Some('Ж'),
-        'Z' => Some('З'),
-        'I' => Some('И'),
-        'J' => Some('Ј'),
-        'K' => Some('К'),
-        'L' => Some('Л'),
-        'M' => Some('М'),
-        'N' => Some('Н'),
-        'O' => Some('О'),
-        'P' => Some('П'),
-        'R' => Some('Р'),
-        'S' => Some('С'),
-        'T' => Some('Т'),
-        'Ć' => Some('Ћ'),
-        'U' => Some('У'),
-        'F' => Some('Ф'),
-        'H' => Some('Х'),
-        'C' => Some('Ц'),
-        'Č' => Some('Ч'),
-        'Š' => Some('Ш'),
-        'a' => Some('а'),
-        'b' => Some('б'),
-        'v' => Some('в'),
-        'g' => Some('г'),
-        'd' => Some('д'),
-        'đ' => Some('ђ'),
-        'e' => Some('е'),
-        'ž' => Some('ж'),
-        'z' => Some('з'),
-        'i' => Some('и'),
-        'j' => Some('ј'),
-        'k' => Some('к'),
-        'l' => Some('л'),
-        'm' => Some('м'),
-        'n' => Some('н'),
-        'o' => Some('о'),
-        'p' => Some('п'),
-        'r' => Some('р'),
-        's' => Some('с'),
-        't' => Some('т'),
-        'ć' => Some('ћ'),
-        'u' => Some('у'),
-        'f' => Some('ф'),
-        'h' => Some('х'),
-        'c' => Some('ц'),
-        'č' => Some('ч'),
-        'š' => Some('ш'),
-        _   => None,
-    }
+    trie
 }
 
-fn cyr_letter_to_lat(letter: char) -> Option<&'static str> {
-    match letter {
-        'А' => Some("A"),
-        'Б' => Some("B"),
-        'В' => Some("V"),
-        'Г' => Some("G"),
-        'Д' => Some("D"),
-        'Ђ' => Some("Đ"),
-        'Е' => Some("E"),
-        'Ж' => Some("Ž"),
-        'З' => Some("Z"),
-        'И' => Some("I"),
-        'Ј' => Some("J"),
-        'К' => Some("K"),
-        'Л' => Some("L"),
-        'Љ' => Some("Lj"),
-        'М' => Some("M"),
-        'Н' => Some("N"),
-        'Њ' => Some("Nj"),
-        'О' => Some("O"),
-        'П' => Some("P"),
-        'Р' => Some("R"),
-        'С' => Some("S"),
-        'Т' => Some("T"),
-        'Ћ' => Some("Ć"),
-        'У' => Some("U"),
-        'Ф' => Some("F"),
-        'Х' => Some("H"),
-        'Ц' => Some("C"),
-        'Ч' => Some("Č"),
-        'Џ' => Some("Dž"),
-        'Ш' => Some("Š"),
-        'а' => Some("a"),
-        'б' => Some("b"),
-        'в' => Some("v"),
-        'г' => Some("g"),
-        'д' => Some("d"),
-        'ђ' => Some("đ"),
-        'е' => Some("e"),
-        'ж' => Some("ž"),
-        'з' => Some("z"),
-        'и' => Some("i"),
-        'ј' => Some("j"),
-        'к' => Some("k"),
-        'л' => Some("l"),
-        'љ' => Some("lj"),
-        'м' => Some("m"),
-        'н' => Some("n"),
-        'њ' => Some("nj"),
-        'о' => Some("o"),
-        'п' => Some("p"),
-        'р' => Some("r"),
-        'с' => Some("s"),
-        'т' => Some("t"),
-        'ћ' => Some("ć"),
-        'у' => Some("u"),
-        'ф' => Some("f"),
-        'х' => Some("h"),
-        'ц' => Some("c"),
-        'ч' => Some("č"),
-        'џ' => Some("dž"),
-        'ш' => Some("š"),
-        _   => None, 
-    }
-}
+impl Converter {
+    pub(crate) fn new(
+        scheme: Scheme,
+        words: &[&str],
+        ignored_latin_words: &[&str],
+        restorer: Option<Restorer>,
+        names: Option<CharTrie<String>>,
+        dialect_words: Option<DialectWords>,
+        config: ConverterConfig,
+    ) -> Converter {
+        let english_digraph_stems = config.english_digraphs_enabled.then(|| {
+            let mut trie = CharTrie::new();
+
+            for stem in ENGLISH_DIGRAPH_STEMS {
+                trie.insert(stem.chars(), ());
+            }
 
-fn search_prefix(trie: &Trie<u8>, prefix: &str) -> Vec<Vec<u8>> {
-    trie.predictive_search(prefix)
-}
+            trie
+        });
 
-fn get_matching_results<'a>(results: &'a [Vec<u8>], lowercase_word: &'a str) -> Vec<&'a str> {
-    unsafe {
-        results
-            .iter()
-            .map(|u8s| str::from_utf8_unchecked(u8s))
-            .filter(|s| s.len() <= lowercase_word.len() && lowercase_word.starts_with(s))
-            .collect()
+        let ignored_latin_words =
+            (!ignored_latin_words.is_empty()).then(|| compile_words(ignored_latin_words));
+
+        Converter {
+            dictionary: compile_words(words),
+            english_digraph_stems,
+            latin_letters: scheme.table().latin_letters(),
+            cyrillic_letters: scheme.table().cyrillic_letters(),
+            escaped_chars: scheme.table().escaped_chars(),
+            ignored_latin_words,
+            restorer,
+            names,
+            dialect_words,
+            scheme,
+            config,
+        }
     }
-}
 
-impl<'a> Converter<'a> {
-    pub fn new(words: &Vec<&str>, config: ConverterConfig<'a>) -> Converter<'a> {
-        let mut trie_builder = TrieBuilder::new();
-        let mut ignored_latin_trie_builder = TrieBuilder::new();
+    /// Returns the scheme used by this converter
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
 
-        for word in words {
-            trie_builder.push(word);
+    /// Converts passed input in the given direction
+    pub fn convert(&self, input: &str, direction: Direction) -> String {
+        match direction {
+            Direction::LatinToCyrillic => self.lat_to_cyr(input),
+            Direction::CyrillicToLatin => self.cyr_to_lat(input),
         }
+    }
 
-        if let Some(ignored_words) = config.ignored_latin_words {
-            for ignored_word in ignored_words {
-                ignored_latin_trie_builder.push(ignored_word);
+    /// Converts the input to the given script. Only words which contain letters of the other
+    /// script are converted, so converting already converted text leaves it unchanged.
+    pub fn to_script(&self, input: &str, script: Script) -> String {
+        let (source, direction) = match script {
+            Script::Cyrillic => (Script::Latin, Direction::LatinToCyrillic),
+            Script::Latin => (Script::Cyrillic, Direction::CyrillicToLatin),
+        };
+
+        let mut converted = String::with_capacity(input.len());
+        let mut copied = 0;
+        let mut span: Option<(usize, usize)> = None;
+
+        for word in detect_script(input).words {
+            let source_letters = match source {
+                Script::Latin => word.latin_letters,
+                Script::Cyrillic => word.cyrillic_letters,
+            };
+
+            if source_letters > 0 {
+                span = Some(span.map_or((word.range.start, word.range.end), |(start, _)| {
+                    (start, word.range.end)
+                }));
+            } else if let Some((start, end)) = span.take() {
+                converted.push_str(&input[copied..start]);
+                converted.push_str(&self.convert(&input[start..end], direction));
+                copied = end;
             }
         }
 
-        Converter {
-            dictionary: trie_builder.build(),
-            ignored_latin_words: if config.ignored_latin_words.is_some() { Some(ignored_latin_trie_builder.build()) } else { None },
-            config,
+        if let Some((start, end)) = span {
+            converted.push_str(&input[copied..start]);
+            converted.push_str(&self.convert(&input[start..end], direction));
+            copied = end;
         }
+
+        converted.push_str(&input[copied..]);
+        converted
     }
 
+    /// Converts passed input from cyrillic to latin script. Uppercase `Љ`, `Њ` and `Џ` are
+    /// converted to uppercase digraph if the neighbouring letter is uppercase (`ЉУБАВ` to
+    /// `LJUBAV`, `ЊИС` to `NJIS`) and to title case digraph otherwise (`Љубав` to `Ljubav`).
+    /// If historical spelling is enabled in the builder, the input is modernised first.
     pub fn cyr_to_lat(&self, input: &str) -> String {
-        let mut converted = String::new();
-        let mut iter = input.chars().peekable();
+        match self.config.historical_yat {
+            Some(yat) => self.cyr_to_lat_letters(&self.modernize_spelling(input, yat).text),
+            None => self.cyr_to_lat_letters(input),
+        }
+    }
+
+    /// Converts passed input from cyrillic to latin script like `cyr_to_lat`, and reports words
+    /// of historical text which couldn't be modernised if historical spelling is enabled
+    pub fn cyr_to_lat_with_report(&self, input: &str) -> ConversionReport {
+        match self.config.historical_yat {
+            Some(yat) => {
+                let report = self.modernize_spelling(input, yat);
+
+                ConversionReport {
+                    text: self.cyr_to_lat_letters(&report.text),
+                    issues: report.issues,
+                }
+            }
+            None => ConversionReport {
+                text: self.cyr_to_lat_letters(input),
+                issues: vec![],
+            },
+        }
+    }
 
-        while let Some(&c) = iter.peek() {
-            match cyr_letter_to_lat(c) {
-                Some(letter) => {
-                    converted.push_str(letter);
+    /// Converts cyrillic letters of the input to latin by the letters of the scheme
+    pub(super) fn cyr_to_lat_letters(&self, input: &str) -> String {
+        let mut converted = String::with_capacity(input.len());
+        let mut previous: Option<char> = None;
+        let mut rest = input;
+
+        while let Some(c) = rest.chars().next() {
+            let mut resolved = None;
+            let matched = self.cyrillic_letters.longest_match_in(rest, |len, letter| {
+                match letter.latin.resolve(previous, || rest[len..].chars().next()) {
+                    Some(latin) => {
+                        resolved = Some((latin, letter.case));
+                        true
+                    }
+                    None => false,
+                }
+            });
+            let byte_len = matched.map_or(c.len_utf8(), |(len, _)| len);
+            let (current, next_rest) = rest.split_at(byte_len);
+
+            match resolved {
+                Some((latin, LetterCase::Title)) if latin.chars().nth(1).is_some() => {
+                    let next = next_rest.chars().next().filter(|c| c.is_alphabetic());
+
+                    let is_upper = match (previous.filter(|c| c.is_alphabetic()), next) {
+                        (_, Some(next)) => next.is_uppercase(),
+                        (Some(previous), None) => previous.is_uppercase(),
+                        (None, None) => self.config.single_letter_digraph_case == DigraphCase::Upper,
+                    };
+
+                    if is_upper {
+                        converted.extend(latin.chars().flat_map(char::to_uppercase));
+                    } else {
+                        converted.push_str(latin);
+                    }
                 }
+                Some((latin, _)) => converted.push_str(latin),
                 None => {
-                    converted.push(c);
+                    if let Some(escape) = self.scheme.table().escape {
+                        if self.escaped_chars.binary_search(&c).is_ok() {
+                            converted.push(escape);
+                        }
+                    }
+
+                    converted.push_str(current);
                 }
             }
 
-            iter.next();
+            // omitted letters (e.g. the apostrophe) don't change the position in the word
+            if resolved.is_none_or(|(latin, _)| !latin.is_empty()) {
+                previous = current.chars().next_back();
+            }
+
+            rest = next_rest;
         }
 
         converted
     }
 
-    /// Converts passed input from latin to cyrillic script. If there are ignored latin words
-    /// defined in the builder it will do the search for each word before converting it.
+    /// Converts passed input from latin to cyrillic script. Input is split into words on Unicode
+    /// word boundaries and everything between the words is kept as it is. If there are ignored
+    /// latin words defined in the builder it will do the search for each word before converting
+    /// it. Parts of hyphenated compounds are looked up in the dictionary separately.
     pub fn lat_to_cyr(&self, input: &str) -> String {
-        let mut converted = String::new();
-        let words: Vec<&str> = input.split(' ').into_iter().collect();
-
-        if let Some(ignored_words) = &self.ignored_latin_words {
-            for (i, word) in words.iter().enumerate() {
-                if ignored_words.exact_match(word) {
-                    converted.push_str(word);
-                } else {
-                    converted.push_str(&self.lat_to_cyr_word(word));
-                }
+        self.lat_to_cyr_with_report(input).text
+    }
 
-                if i != words.len() - 1 {
-                    converted.push(' ');
-                }
+    /// Converts passed input from latin to cyrillic script like `lat_to_cyr`, and reports words
+    /// whose diacritics couldn't be restored if diacritic restoration is enabled.
+    ///
+    /// With a scheme which escapes latin characters (`Scheme::SerbianReversible`), escaped
+    /// characters are copied as they are and split the words around them, so ignored words,
+    /// diacritic restoration, foreign word detection and name transcription see only the parts
+    /// of such words.
+    pub fn lat_to_cyr_with_report(&self, input: &str) -> ConversionReport {
+        let mut converted = String::with_capacity(input.len() * 2);
+        let mut issues = vec![];
+        let mut start = 0;
+
+        if let Some(escape) = self.scheme.table().escape {
+            while let Some(index) = input[start..].find(escape) {
+                let text = &input[start..start + index];
+                let escaped = &input[start + index + escape.len_utf8()..];
+                let escaped = &escaped[..escaped.chars().next().map_or(0, char::len_utf8)];
+
+                self.lat_to_cyr_part(text, start, &mut issues, &mut converted);
+                converted.push_str(escaped);
+                start += index + escape.len_utf8() + escaped.len();
             }
-        } else {
-            for (i, word) in words.iter().enumerate() {
-                converted.push_str(&self.lat_to_cyr_word(word));
+        }
+
+        self.lat_to_cyr_part(&input[start..], start, &mut issues, &mut converted);
 
-                if i != words.len() - 1 {
-                    converted.push(' ');
+        ConversionReport {
+            text: converted,
+            issues,
+        }
+    }
+
+    /// Converts the part of the input starting at byte `start`, which has no escaped characters
+    fn lat_to_cyr_part(
+        &self,
+        input: &str,
+        start: usize,
+        issues: &mut Vec<Issue>,
+        converted: &mut String,
+    ) {
+        let first_issue = issues.len();
+
+        self.for_each_latin_part(input, issues, |part| match part {
+            LatinPart::Kept(text) => converted.push_str(text),
+            LatinPart::Word(word) => self.lat_to_cyr_word(&word, converted),
+            LatinPart::Name(_, transcription) => converted.push_str(&transcription),
+        });
+
+        for issue in &mut issues[first_issue..] {
+            issue.range = issue.range.start + start..issue.range.end + start;
+        }
+    }
+
+    /// Converts passed input from latin to cyrillic script like `lat_to_cyr`, but fails with the
+    /// list of words which have letters outside of the alphabet if `ForeignLetterPolicy::Fail`
+    /// is set in the builder
+    pub fn try_lat_to_cyr(&self, input: &str) -> Result<String, ForeignLettersError> {
+        let report = self.lat_to_cyr_with_report(input);
+
+        if self.config.foreign_letter_policy == ForeignLetterPolicy::Fail {
+            let words: Vec<Issue> = report
+                .issues
+                .into_iter()
+                .filter(|issue| issue.kind == IssueKind::ForeignLetters)
+                .collect();
+
+            if !words.is_empty() {
+                return Err(ForeignLettersError { words });
+            }
+        }
+
+        Ok(report.text)
+    }
+
+    /// Restores diacritics of latin words written without them (`zivot` to `život`) without
+    /// converting the text to cyrillic. The text is returned unchanged if diacritic restoration
+    /// isn't enabled in the builder.
+    pub fn restore_diacritics(&self, input: &str) -> ConversionReport {
+        let mut restored = String::with_capacity(input.len());
+        let mut issues = vec![];
+
+        self.for_each_latin_part(input, &mut issues, |part| match part {
+            LatinPart::Kept(text) | LatinPart::Name(text, _) => restored.push_str(text),
+            LatinPart::Word(word) => restored.push_str(&word),
+        });
+
+        ConversionReport {
+            text: restored,
+            issues,
+        }
+    }
+
+    /// Calls `f` for every part of the input: separators and ignored words, foreign names found
+    /// in the lexicon and parts of hyphenated words with restored diacritics. If language
+    /// identification is enabled, sentences identified as foreign are passed whole as kept.
+    fn for_each_latin_part<F>(&self, input: &str, issues: &mut Vec<Issue>, mut f: F)
+    where
+        F: FnMut(LatinPart),
+    {
+        let Some(threshold) = self.config.language_confidence_threshold else {
+            return self.for_each_word_part(input, 0, issues, &mut f);
+        };
+
+        for (start, sentence) in input.split_sentence_bound_indices() {
+            match identify_language(sentence) {
+                Some(guess)
+                    if guess.language != Language::Serbian && guess.confidence >= threshold =>
+                {
+                    let text = sentence.trim_end();
+
+                    issues.push(Issue {
+                        word: text.to_string(),
+                        range: start..start + text.len(),
+                        kind: IssueKind::ForeignSentence(guess.language),
+                    });
+                    f(LatinPart::Kept(sentence));
                 }
+                _ => self.for_each_word_part(sentence, start, issues, &mut f),
             }
         }
+    }
 
-        converted
+    /// Calls `f` for every part of the input like `for_each_latin_part`, with issues reported at
+    /// byte ranges shifted by `offset`
+    fn for_each_word_part<F>(
+        &self,
+        input: &str,
+        offset: usize,
+        issues: &mut Vec<Issue>,
+        f: &mut F,
+    ) where
+        F: FnMut(LatinPart),
+    {
+        for token in self.latin_tokens(input) {
+            let text = &input[token.range.clone()];
+            let range = (offset + token.range.start)..(offset + token.range.end);
+
+            if token.kind == TokenKind::Separator || self.is_ignored_latin_word(text) {
+                f(LatinPart::Kept(text));
+            } else if let Some(transcription) = self.lexicon_name(text) {
+                f(LatinPart::Name(text, transcription));
+            } else if self.is_skipped_foreign_word(text) {
+                issues.push(Issue {
+                    word: text.to_string(),
+                    range,
+                    kind: IssueKind::ForeignLetters,
+                });
+                f(LatinPart::Kept(text));
+            } else if let Some(rule) = self.foreign_word_rule(text) {
+                issues.push(Issue {
+                    word: text.to_string(),
+                    range,
+                    kind: IssueKind::ForeignWord(rule),
+                });
+                f(LatinPart::Kept(text));
+            } else {
+                let mut start = range.start;
+
+                for part in text.split_inclusive(HYPHENS) {
+                    f(LatinPart::Word(self.restore_word(part, start, issues)));
+                    start += part.len();
+                }
+            }
+        }
     }
 
-    fn lat_to_cyr_word(&self, input: &str) -> String {
-        let mut current_position: usize = 0;
-        let mut converted = String::new();
-        let lowercase_word = &input.to_lowercase();
-        let chars: Vec<char> = input.chars().collect();
+    /// Returns `true` if the word has letters outside of the alphabet and the policy is to keep
+    /// such words unconverted
+    fn is_skipped_foreign_word(&self, word: &str) -> bool {
+        matches!(
+            self.config.foreign_letter_policy,
+            ForeignLetterPolicy::SkipWord | ForeignLetterPolicy::Fail
+        ) && word.chars().any(|c| {
+            self.latin_letters
+                .get([c])
+                .is_some_and(|letter| letter.kind == LetterKind::Foreign)
+        })
+    }
 
-        for (i, c) in chars.iter().enumerate() {
-            if i < current_position {
-                continue;
+    /// Returns the first enabled rule of the foreign word detection which recognises the word
+    fn foreign_word_rule(&self, word: &str) -> Option<ForeignWordRule> {
+        let is_foreign_letter = |c: char| {
+            Script::of(c) == Some(Script::Latin)
+                && !self
+                    .latin_letters
+                    .get([c])
+                    .is_some_and(|letter| letter.kind != LetterKind::Foreign)
+        };
+        let has_impossible_sequence = |word: &str| {
+            let lowercase = word.to_lowercase();
+
+            self.scheme
+                .table()
+                .impossible_sequences
+                .iter()
+                .any(|sequence| lowercase.contains(sequence))
+        };
+
+        self.config.foreign_word_rules.iter().copied().find(|rule| match rule {
+            ForeignWordRule::ForeignLetters => word.chars().any(is_foreign_letter),
+            ForeignWordRule::CamelCase => word
+                .chars()
+                .zip(word.chars().skip(1))
+                .any(|(previous, c)| previous.is_lowercase() && c.is_uppercase()),
+            ForeignWordRule::DigitsWithLetters => {
+                word.chars().any(|c| c.is_numeric()) && word.chars().any(char::is_alphabetic)
             }
+            ForeignWordRule::ImpossibleSequences => has_impossible_sequence(word),
+            ForeignWordRule::Acronyms => {
+                let stem = word.split(HYPHENS).next().unwrap_or_default();
+
+                stem.chars().count() > 1
+                    && stem
+                        .chars()
+                        .all(|c| c.is_uppercase() && Script::of(c) == Some(Script::Latin))
+                    && (self.config.foreign_acronyms.iter().any(|acronym| acronym == stem)
+                        || stem.chars().any(is_foreign_letter)
+                        || has_impossible_sequence(stem))
+            }
+        })
+    }
 
-            let next_char = chars.get(i + 1);
-            let current_word_segment = lowercase_word.char_slice(0, current_position);
+    fn restore_word<'a>(
+        &self,
+        part: &'a str,
+        start: usize,
+        issues: &mut Vec<Issue>,
+    ) -> Cow<'a, str> {
+        let Some(restorer) = &self.restorer else {
+            return Cow::Borrowed(part);
+        };
+
+        let word = part.trim_end_matches(HYPHENS);
+
+        match restorer.restore(word, start, issues) {
+            Cow::Borrowed(_) => Cow::Borrowed(part),
+            Cow::Owned(restored) => Cow::Owned(restored + &part[word.len()..]),
+        }
+    }
 
-            match &c {
-                'n' => match next_char {
-                    Some(&'j') => {
-                        if !self.is_prefix_in_dictionary(current_word_segment, lowercase_word) {
-                            converted.push('њ');
-                            current_position += 2;
-                            continue;
-                        }
+    /// Splits input into words, attaching punctuation which is a part of the latin letters of the
+    /// scheme (e.g. `` ` `` for `ь` in GOST 7.79) to the preceding word, and joining it with the
+    /// following word if there is nothing else between them
+    fn latin_tokens(&self, input: &str) -> Vec<Token> {
+        let mut tokens: Vec<Token> = vec![];
+        let mut is_joining = false;
 
-                        converted.push_str("нј");
-                        current_position += 1;
-                    }
-                    _ => converted.push('н'),
-                },
-                'N' => match next_char {
-                    Some(&'j') | Some(&'J') => {
-                        if !self.is_prefix_in_dictionary(current_word_segment, lowercase_word) {
-                            converted.push('Њ');
-                            current_position += 2;
-                            continue;
-                        }
+        for token in tokenize(input) {
+            let previous_word = tokens.last_mut().filter(|last| last.kind == TokenKind::Word);
 
-                        converted.push('Н');
-                        converted.push(lat_letter_to_cyr(*next_char.unwrap()).unwrap());
-                        current_position += 1;
-                    }
-                    _ => converted.push('Н'),
-                },
-                'l' => match next_char {
-                    Some(&'j') => {
-                        converted.push('љ');
-                        current_position += 2;
-                        continue;
-                    }
-                    _ => converted.push('л'),
-                },
-                'L' => match next_char {
-                    Some(&'j') | Some(&'J') => {
-                        converted.push('Љ');
-                        current_position += 2;
-                        continue;
+            match (token.kind, previous_word) {
+                (TokenKind::Word, Some(previous)) if is_joining => {
+                    previous.range.end = token.range.end;
+                    is_joining = false;
+                }
+                (TokenKind::Separator, Some(previous)) => {
+                    let text = &input[token.range.clone()];
+                    let attached = text
+                        .find(|c| !self.latin_letters.starts_key(c))
+                        .unwrap_or(text.len());
+
+                    previous.range.end += attached;
+                    is_joining = attached == text.len();
+
+                    if !is_joining {
+                        tokens.push(Token {
+                            kind: TokenKind::Separator,
+                            range: (token.range.start + attached)..token.range.end,
+                        });
                     }
-                    _ => converted.push('Л'),
-                },
-                'd' => match next_char {
-                    Some(&'j') => {
-                        if self.config.dj_conversion_enabled
-                            && !self.is_prefix_in_dictionary(current_word_segment, lowercase_word)
-                        {
-                            converted.push('ђ');
-                            current_position += 2;
-                            continue;
-                        }
+                }
+                _ => tokens.push(token),
+            }
+        }
 
-                        converted.push('д');
-                        converted.push(lat_letter_to_cyr(*next_char.unwrap()).unwrap());
-                        current_position += 1;
-                    }
-                    Some(&'ž') => {
-                        if !self.is_prefix_in_dictionary(current_word_segment, lowercase_word) {
-                            converted.push('џ');
-                            current_position += 2;
-                            continue;
-                        }
+        tokens
+    }
 
-                        converted.push_str("дж");
-                        current_position += 1;
-                    }
-                    Some(&'z') => {
-                        if self.config.dz_conversion_enabled {
-                            converted.push('џ');
-                            current_position += 2;
-                            continue;
-                        }
+    fn is_ignored_latin_word(&self, word: &str) -> bool {
+        self.ignored_latin_words
+            .as_ref()
+            .is_some_and(|ignored_words| ignored_words.exact_match(word))
+    }
 
-                        converted.push_str("dz");
-                        current_position += 1;
-                    }
-                    _ => converted.push('д'),
-                },
-                'D' => match next_char {
-                    Some(&'j') | Some(&'J') => {
-                        if self.config.dj_conversion_enabled
-                            && !self.is_prefix_in_dictionary(current_word_segment, lowercase_word)
-                        {
-                            converted.push('Ђ');
-                            current_position += 2;
-                            continue;
-                        }
+    /// Converts a single word by walking it once. The dictionary is consulted only once per word
+    /// for the longest prefix it contains; a digraph is kept literal if that prefix reaches the
+    /// position where the digraph starts.
+    pub(super) fn lat_to_cyr_word(&self, input: &str, converted: &mut String) {
+        self.convert_latin_word(input, false, converted);
+    }
 
-                        converted.push('Д');
-                        converted.push(lat_letter_to_cyr(*next_char.unwrap()).unwrap());
-                        current_position += 1;
-                    }
-                    Some(&'ž') | Some(&'Ž') => {
-                        if !self.is_prefix_in_dictionary(current_word_segment, lowercase_word) {
-                            converted.push('Џ');
-                            current_position += 2;
-                            continue;
-                        }
+    /// Converts latin letters of a word written with the alphabet of the scheme to cyrillic,
+    /// without reading bald latin (`dj`, `dz`), English digraphs and foreign letters
+    pub(super) fn lat_to_cyr_letters(&self, input: &str, converted: &mut String) {
+        self.convert_latin_word(input, true, converted);
+    }
+
+    fn convert_latin_word(&self, input: &str, is_alphabet_only: bool, converted: &mut String) {
+        let literal_len = self
+            .dictionary
+            .longest_prefix_len(input.chars().map(|c| c.to_lowercase().next().unwrap_or(c)))
+            .unwrap_or(0);
+        // an English digraph which is the whole word is an abbreviation (`tj.`), not a letter
+        let is_digraph_word = input.chars().nth(2).is_none();
+        let stem_ranges = self.english_digraph_stem_ranges(input);
+
+        let mut rest = input;
+        let mut previous = None;
+        let mut position = 0;
+
+        while let Some(c) = rest.chars().next() {
+            if Some(c) == self.scheme.table().escape {
+                let literal = rest[c.len_utf8()..].chars().next().map_or(0, char::len_utf8);
+
+                converted.push_str(&rest[c.len_utf8()..][..literal]);
+                rest = &rest[c.len_utf8() + literal..];
+                continue;
+            }
+
+            let mut resolved = None;
+            let matched = self.latin_letters.longest_match_in(rest, |len, letter| {
+                let is_guarded = len > c.len_utf8() || letter.kind == LetterKind::Foreign;
+                // lowercase `dz` is converted without consulting the dictionary, and kept in
+                // latin script if `dz` conversion is disabled
+                let is_lowercase_dz = letter.kind == LetterKind::Dz && &rest[..len] == "dz";
+
+                if is_guarded
+                    && !is_lowercase_dz
+                    && !self.is_letter_enabled(letter.kind, position, literal_len)
+                {
+                    return false;
+                }
+
+                let is_alphabet = matches!(letter.kind, LetterKind::Plain | LetterKind::Guarded);
+
+                if is_alphabet_only && !is_alphabet {
+                    return false;
+                }
+
+                if is_lowercase_dz && !self.config.dz_conversion_enabled {
+                    resolved = Some("dz");
+                    return true;
+                }
+
+                if letter.kind == LetterKind::English {
+                    let end = position + rest[..len].chars().count();
+                    let is_in_stem = stem_ranges
+                        .iter()
+                        .any(|&(start, stem_end)| start <= position && end <= stem_end);
 
-                        converted.push('Д');
-                        converted.push(lat_letter_to_cyr(*next_char.unwrap()).unwrap());
-                        current_position += 1;
+                    if is_digraph_word || is_in_stem {
+                        return false;
                     }
-                    Some(&'z') | Some(&'Z') => {
-                        if self.config.dz_conversion_enabled
-                            && !self.is_prefix_in_dictionary(current_word_segment, lowercase_word)
-                        {
-                            converted.push('Џ');
-                            current_position += 2;
-                            continue;
-                        }
+                }
 
-                        converted.push('Д');
-                        converted.push(lat_letter_to_cyr(*next_char.unwrap()).unwrap());
-                        current_position += 1;
+                match letter.cyrillic.resolve(previous, || rest[len..].chars().next()) {
+                    Some(cyrillic) => {
+                        resolved = Some(cyrillic);
+                        true
+                    }
+                    None => false,
+                }
+            });
+            let byte_len = matched.map_or(c.len_utf8(), |(len, _)| len);
+            let (current, next_rest) = rest.split_at(byte_len);
+
+            match resolved {
+                // uppercase letter transcribed with several letters (`X` to `Кс`)
+                Some(cyrillic)
+                    if c.is_uppercase()
+                        && current.len() == c.len_utf8()
+                        && cyrillic.chars().nth(1).is_some() =>
+                {
+                    let next = next_rest.chars().next().filter(|c| c.is_alphabetic());
+                    let is_upper = match (previous.filter(|c| c.is_alphabetic()), next) {
+                        (_, Some(next)) => next.is_uppercase(),
+                        (Some(previous), None) => previous.is_uppercase(),
+                        (None, None) => false,
+                    };
+
+                    if is_upper {
+                        converted.push_str(&cyrillic.to_uppercase());
+                    } else {
+                        converted.push_str(cyrillic);
                     }
-                    _ => converted.push('Д'),
-                },
-                _ => match lat_letter_to_cyr(*c) {
-                    Some(letter) => converted.push(letter),
-                    None => converted.push(*c),
-                },
+                }
+                Some(cyrillic) => converted.push_str(cyrillic),
+                None => converted.push_str(current),
             }
 
-            current_position += 1;
+            position += current.chars().count();
+            previous = current.chars().next_back();
+            rest = next_rest;
         }
-
-        converted
     }
 
-    fn is_prefix_in_dictionary(&self, prefix: &str, lowercase_word: &str) -> bool {
-        let results = search_prefix(&self.dictionary, prefix);
-
-        if results.is_empty() {
-            return false;
-        }
-
-        let matching_results = get_matching_results(&results, lowercase_word);
+    /// Returns ranges (in characters) of Serbian stems in the word whose digraphs aren't read as
+    /// English digraphs (`shod` in `neshodan`, `letje` in `obletjeti`)
+    fn english_digraph_stem_ranges(&self, input: &str) -> Vec<(usize, usize)> {
+        let Some(stems) = &self.english_digraph_stems else {
+            return vec![];
+        };
+        let lowercase: Vec<char> = input
+            .chars()
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+            .collect();
+
+        (0..lowercase.len())
+            .filter_map(|start| {
+                let len = stems.longest_prefix_len(lowercase[start..].iter().copied())?;
+                Some((start, start + len))
+            })
+            .collect()
+    }
 
-        if matching_results.is_empty() {
-            return false;
+    fn is_letter_enabled(&self, kind: LetterKind, position: usize, literal_len: usize) -> bool {
+        let is_literal = literal_len >= position.max(1);
+
+        match kind {
+            LetterKind::Plain => true,
+            LetterKind::Guarded => !is_literal,
+            LetterKind::Dj => self.config.dj_conversion_enabled && !is_literal,
+            LetterKind::Dz => self.config.dz_conversion_enabled && !is_literal,
+            LetterKind::English => self.config.english_digraphs_enabled && !is_literal,
+            LetterKind::Foreign => {
+                self.config.foreign_letter_policy == ForeignLetterPolicy::Transcribe
+            }
         }
-
-        true
     }
 }
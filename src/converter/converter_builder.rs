@@ -1,74 +1,331 @@
-use super::{Converter, ConverterBuilder, ConverterConfig};
-use crate::constants::{IJEKAVIAN_PREFIXES, LITERAL_PREFIXES};
+use super::dialect::compile_dialect_words;
+use super::names::compile_lexicon;
+use super::restoration::Restorer;
+use super::{
+    Converter, ConverterBuilder, ConverterConfig, Dialect, DigraphCase, ForeignLetterPolicy,
+    ForeignWordRule, RestorationFallback, Scheme, SourceLanguage,
+};
+use crate::constants::IJEKAVIAN_PREFIXES;
 
-impl<'a> Default for ConverterBuilder<'a> {
+impl Default for ConverterBuilder {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<'a> ConverterBuilder<'a> {
-    pub fn new() -> ConverterBuilder<'a> {
+impl ConverterBuilder {
+    pub fn new() -> ConverterBuilder {
         ConverterBuilder {
-            dictionary: Vec::from(LITERAL_PREFIXES),
+            scheme: Scheme::Serbian,
+            dictionary: vec![],
             ignored_latin_words: vec![],
             dj_conversion_enabled: false,
             dz_conversion_enabled: false,
+            english_digraphs_enabled: false,
+            foreign_letter_policy: ForeignLetterPolicy::Keep,
+            foreign_word_rules: vec![],
+            foreign_acronyms: vec![],
+            language_identification_enabled: false,
+            language_confidence_threshold: 0.95,
             ijekavian_inclusion_enabled: false,
+            single_letter_digraph_case: DigraphCase::Title,
+            diacritic_restoration_enabled: false,
+            restoration_fallback: RestorationFallback::Keep,
+            restoration_words: vec![],
+            name_transcription_enabled: false,
+            name_transcriptions: vec![],
+            foreign_names: vec![],
+            dialect_words: vec![],
+            historical_yat: None,
         }
     }
 
+    /// Sets the scheme used for the conversion. Each scheme comes with its own dictionary of
+    /// literal prefixes, which is extended by `extend_dictionary`. Defaults to `Scheme::Serbian`.
+    pub fn use_scheme(&mut self, scheme: Scheme) -> &mut ConverterBuilder {
+        self.scheme = scheme;
+        self
+    }
+
     /// Adds given prefixes to the dictionary which will be used by the `Converter`
-    pub fn extend_dictionary(&mut self, prefixes: &mut Vec<&'a str>) -> &mut ConverterBuilder<'a> {
-        self.dictionary.append(prefixes);
+    pub fn extend_dictionary<I, S>(&mut self, prefixes: I) -> &mut ConverterBuilder
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.dictionary.extend(prefixes.into_iter().map(Into::into));
         self
     }
 
     /// Adds words which will be skipped during conversion process when converting from latin to cyrillic script
-    pub fn add_ignored_latin_words(&mut self, words: &mut Vec<&'a str>) -> &mut ConverterBuilder<'a> {
-        self.ignored_latin_words.append(words);
+    pub fn add_ignored_latin_words<I, S>(&mut self, words: I) -> &mut ConverterBuilder
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.ignored_latin_words.extend(words.into_iter().map(Into::into));
         self
     }
 
-    /// Enables conversion from `dj` to `ђ` for words which don't have prefix in the dictionary
-    pub fn enable_dj_conversion(&mut self) -> &mut ConverterBuilder<'a> {
+    /// Enables conversion from `dj` to `ђ` (Serbian and Montenegrin schemes only) for words
+    /// which don't have prefix in the dictionary
+    pub fn enable_dj_conversion(&mut self) -> &mut ConverterBuilder {
         self.dj_conversion_enabled = true;
         self
     }
 
-    /// Enables conversion from `dz` to `џ` for words which don't have prefix in the dictionary
-    pub fn enable_dz_conversion(&mut self) -> &mut ConverterBuilder<'a> {
+    /// Enables conversion from `dz` to `џ` (Serbian and Montenegrin schemes only) for words
+    /// which don't have prefix in the dictionary
+    pub fn enable_dz_conversion(&mut self) -> &mut ConverterBuilder {
         self.dz_conversion_enabled = true;
         self
     }
 
     /// Enables both, conversions from `dj` to `ђ` and from `dz` to `џ`
-    pub fn enable_bald_latin(&mut self) -> &mut ConverterBuilder<'a> {
+    pub fn enable_bald_latin(&mut self) -> &mut ConverterBuilder {
         self.enable_dj_conversion();
         self.enable_dz_conversion();
         self
     }
 
+    /// Enables reading English-style digraphs `sh`, `ch`, `zh`, `cj` and `tj` as `ш`, `ч`, `ж`,
+    /// `ћ` and `ћ` (Serbian and Montenegrin schemes only) for words which don't have prefix in
+    /// the dictionary. Digraphs inside Serbian stems with these sequences (`ishod`, `ushićen`,
+    /// `obletjeti`) are kept, while loan words (`schema`, `shop`, `Zhou`) can be added with
+    /// `extend_dictionary`. Two letter words (`tj.`) are kept as they are.
+    pub fn enable_english_digraphs(&mut self) -> &mut ConverterBuilder {
+        self.english_digraphs_enabled = true;
+        self
+    }
+
+    /// Sets what to do with latin letters which aren't in the alphabet of the scheme (`q`, `w`,
+    /// `x` and `y` in Serbian, Montenegrin and Macedonian schemes). Defaults to
+    /// `ForeignLetterPolicy::Keep`.
+    pub fn set_foreign_letter_policy(
+        &mut self,
+        policy: ForeignLetterPolicy,
+    ) -> &mut ConverterBuilder {
+        self.foreign_letter_policy = policy;
+        self
+    }
+
+    /// Enables detection of foreign words (brand names, code identifiers, English terms), which
+    /// are kept in latin when converting to cyrillic and reported by
+    /// `Converter::lat_to_cyr_with_report`. All rules of `ForeignWordRule` are enabled.
+    pub fn enable_foreign_word_detection(&mut self) -> &mut ConverterBuilder {
+        for rule in [
+            ForeignWordRule::Acronyms,
+            ForeignWordRule::ForeignLetters,
+            ForeignWordRule::CamelCase,
+            ForeignWordRule::DigitsWithLetters,
+            ForeignWordRule::ImpossibleSequences,
+        ] {
+            self.enable_foreign_word_rule(rule);
+        }
+
+        self
+    }
+
+    /// Enables a single rule of the foreign word detection
+    pub fn enable_foreign_word_rule(&mut self, rule: ForeignWordRule) -> &mut ConverterBuilder {
+        if !self.foreign_word_rules.contains(&rule) {
+            self.foreign_word_rules.push(rule);
+        }
+
+        self
+    }
+
+    /// Disables a single rule of the foreign word detection
+    pub fn disable_foreign_word_rule(&mut self, rule: ForeignWordRule) -> &mut ConverterBuilder {
+        self.foreign_word_rules.retain(|enabled| *enabled != rule);
+        self
+    }
+
+    /// Adds uppercase words (`README`, `TODO`) which are recognised as foreign by the
+    /// `ForeignWordRule::Acronyms` rule, even though they are spelled with letters of the scheme
+    pub fn extend_foreign_acronyms<I, S>(&mut self, words: I) -> &mut ConverterBuilder
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.foreign_acronyms.extend(words.into_iter().map(Into::into));
+        self
+    }
+
+    /// Enables language identification when converting to cyrillic. The input is split into
+    /// sentences, and sentences identified as English, German, French or Italian are kept in
+    /// latin and reported by `Converter::lat_to_cyr_with_report`. Sentences whose language isn't
+    /// certain are converted.
+    pub fn enable_language_identification(&mut self) -> &mut ConverterBuilder {
+        self.language_identification_enabled = true;
+        self
+    }
+
+    /// Sets the confidence, between `0.0` and `1.0`, from which a sentence identified as foreign
+    /// is kept in latin. Defaults to `0.95`.
+    pub fn set_language_confidence_threshold(
+        &mut self,
+        threshold: f64,
+    ) -> &mut ConverterBuilder {
+        self.language_confidence_threshold = threshold;
+        self
+    }
+
     /// Enables including ijekavian words (otherwise they won't have `dj` converted to `ђ` if
     /// dj conversion is enabled)
-    pub fn enable_ijekavian_inclusion(&mut self) -> &mut ConverterBuilder<'a> {
+    pub fn enable_ijekavian_inclusion(&mut self) -> &mut ConverterBuilder {
         self.ijekavian_inclusion_enabled = true;
         self
     }
 
-    pub fn build(&mut self) -> Converter {
-        if self.ijekavian_inclusion_enabled {
-            for prefix in IJEKAVIAN_PREFIXES {
-                let _ = &self.dictionary.push(prefix);
-            }
+    /// Sets case of the latin digraph converted from the uppercase `Љ`, `Њ` or `Џ` which is the
+    /// only letter of the word (e.g. `Џ` as an abbreviation), where it can't be deduced from the
+    /// neighbouring letters. Defaults to `DigraphCase::Title`.
+    pub fn set_single_letter_digraph_case(&mut self, case: DigraphCase) -> &mut ConverterBuilder {
+        self.single_letter_digraph_case = case;
+        self
+    }
+
+    /// Enables restoring diacritics of latin words written without them (`zivot` to `život`,
+    /// `cevapcici` to `ćevapčići`) before converting them to cyrillic, using the bundled list of
+    /// Serbian words. Words which aren't in the list are kept as they are.
+    pub fn enable_diacritic_restoration(&mut self) -> &mut ConverterBuilder {
+        self.diacritic_restoration_enabled = true;
+        self
+    }
+
+    /// Sets what to do with words whose diacritics can be restored in several ways. Defaults to
+    /// `RestorationFallback::Keep`.
+    pub fn set_restoration_fallback(
+        &mut self,
+        fallback: RestorationFallback,
+    ) -> &mut ConverterBuilder {
+        self.restoration_fallback = fallback;
+        self
+    }
+
+    /// Adds word forms with diacritics to the list used for restoring diacritics. They are
+    /// preferred over the bundled words with the same form without diacritics.
+    pub fn extend_restoration_words<I, S>(&mut self, words: I) -> &mut ConverterBuilder
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.restoration_words.extend(words.into_iter().map(Into::into));
+        self
+    }
+
+    /// Enables transcription of foreign names (`Shakespeare` to `Шекспир`) when converting to
+    /// cyrillic. Capitalised words found in the bundled lexicon of English, German, French and
+    /// Italian names, or added by `extend_name_lexicon` and `add_foreign_names`, are transcribed
+    /// instead of transliterated.
+    pub fn enable_name_transcription(&mut self) -> &mut ConverterBuilder {
+        self.name_transcription_enabled = true;
+        self
+    }
+
+    /// Adds names with their cyrillic transcriptions to the lexicon of foreign names, replacing
+    /// bundled transcriptions of the same names
+    pub fn extend_name_lexicon<I, S, T>(&mut self, transcriptions: I) -> &mut ConverterBuilder
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: Into<String>,
+        T: Into<String>,
+    {
+        self.name_transcriptions.extend(
+            transcriptions
+                .into_iter()
+                .map(|(name, transcription)| (name.into(), transcription.into())),
+        );
+        self
+    }
+
+    /// Adds names to the lexicon of foreign names, which are transcribed by the rules of the
+    /// given language
+    pub fn add_foreign_names<I, S>(
+        &mut self,
+        language: SourceLanguage,
+        names: I,
+    ) -> &mut ConverterBuilder
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.foreign_names.extend(names.into_iter().map(|name| (language, name.into())));
+        self
+    }
+
+    /// Adds ekavian words with their ijekavian forms to the list used by
+    /// `Converter::convert_dialect`, replacing bundled forms of the same words
+    pub fn extend_dialect_words<I, S, T>(&mut self, pairs: I) -> &mut ConverterBuilder
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: Into<String>,
+        T: Into<String>,
+    {
+        self.dialect_words.extend(
+            pairs
+                .into_iter()
+                .map(|(ekavian, ijekavian)| (ekavian.into(), ijekavian.into())),
+        );
+        self
+    }
+
+    /// Enables modernising historical cyrillic text (`ѣ`, `ы`, `ъ`, `і`, `ѳ` and the
+    /// Slavonic-Serbian spelling) before converting it to latin, with yat written in the given
+    /// pronunciation. See `Converter::modernize_spelling`.
+    pub fn enable_historical_spelling(&mut self, yat: Dialect) -> &mut ConverterBuilder {
+        self.historical_yat = Some(yat);
+        self
+    }
+
+    /// Builds the `Converter` with the current options. The builder is left unchanged, so it
+    /// can be changed further and used to build another converter.
+    pub fn build(&self) -> Converter {
+        let mut dictionary: Vec<&str> = self.scheme.table().literal_prefixes.to_vec();
+        dictionary.extend(self.dictionary.iter().map(String::as_str));
+
+        if self.ijekavian_inclusion_enabled || self.scheme == Scheme::Montenegrin {
+            dictionary.extend(IJEKAVIAN_PREFIXES);
         }
 
+        let ignored_latin_words: Vec<&str> =
+            self.ignored_latin_words.iter().map(String::as_str).collect();
+
         let config = ConverterConfig {
             dj_conversion_enabled: self.dj_conversion_enabled,
             dz_conversion_enabled: self.dz_conversion_enabled,
-            ignored_latin_words: if self.ignored_latin_words.is_empty() { None } else { Some(&self.ignored_latin_words) },
+            english_digraphs_enabled: self.english_digraphs_enabled,
+            foreign_letter_policy: self.foreign_letter_policy,
+            foreign_word_rules: self.foreign_word_rules.clone(),
+            foreign_acronyms: self.foreign_acronyms.clone(),
+            language_confidence_threshold: self
+                .language_identification_enabled
+                .then_some(self.language_confidence_threshold),
+            historical_yat: self.historical_yat,
+            single_letter_digraph_case: self.single_letter_digraph_case,
         };
 
-        Converter::new(&self.dictionary, config)
+        let restorer = self.diacritic_restoration_enabled.then(|| {
+            let words: Vec<&str> = self.restoration_words.iter().map(String::as_str).collect();
+            Restorer::new(&words, self.restoration_fallback)
+        });
+
+        let names = self
+            .name_transcription_enabled
+            .then(|| compile_lexicon(&self.name_transcriptions, &self.foreign_names));
+
+        let dialect_words = (!self.dialect_words.is_empty())
+            .then(|| compile_dialect_words(&self.dialect_words));
+
+        Converter::new(
+            self.scheme,
+            &dictionary,
+            &ignored_latin_words,
+            restorer,
+            names,
+            dialect_words,
+            config,
+        )
     }
 }
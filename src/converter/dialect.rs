@@ -0,0 +1,266 @@
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+use super::automaton::CharTrie;
+use super::scheme::match_case;
+use super::{Converter, Dialect};
+use crate::report::{ConversionReport, Issue, IssueKind};
+use crate::script::Script;
+use crate::tokenizer::{tokenize, TokenKind};
+
+/// Bundled list of ekavian words with their ijekavian forms, the format is described in the file
+/// itself
+const WORDS: &str = include_str!("../../data/dialect_words.txt");
+
+/// Ekavian and ijekavian endings of the paradigms used in the word list
+#[allow(clippy::type_complexity)]
+const PARADIGMS: [(&str, &[&str], &[&str]); 9] = [
+    (
+        "f",
+        &["a", "e", "i", "u", "om", "ama"],
+        &["a", "e", "i", "u", "om", "ama"],
+    ),
+    (
+        "m",
+        &["", "a", "u", "om", "e", "i", "ima"],
+        &["", "a", "u", "om", "e", "i", "ima"],
+    ),
+    (
+        "n",
+        &["o", "a", "u", "om", "ima"],
+        &["o", "a", "u", "om", "ima"],
+    ),
+    (
+        "ne",
+        &["e", "a", "u", "em", "ima"],
+        &["e", "a", "u", "em", "ima"],
+    ),
+    (
+        "adj",
+        &[
+            "", "a", "o", "e", "i", "u", "og", "oj", "om", "im", "ih", "ima",
+        ],
+        &[
+            "", "a", "o", "e", "i", "u", "og", "oj", "om", "im", "ih", "ima",
+        ],
+    ),
+    (
+        "ati",
+        &[
+            "ati", "am", "aš", "a", "amo", "ate", "aju", "ao", "ala", "alo", "ali", "ajući",
+        ],
+        &[
+            "ati", "am", "aš", "a", "amo", "ate", "aju", "ao", "ala", "alo", "ali", "ajući",
+        ],
+    ),
+    (
+        "iti",
+        &[
+            "iti", "im", "iš", "i", "imo", "ite", "e", "io", "ila", "ilo", "ili", "eći",
+        ],
+        &[
+            "iti", "im", "iš", "i", "imo", "ite", "e", "io", "ila", "ilo", "ili", "eći",
+        ],
+    ),
+    (
+        "eti",
+        &[
+            "eti", "im", "iš", "i", "imo", "ite", "e", "eo", "ela", "elo", "eli", "eći",
+        ],
+        &[
+            "jeti", "im", "iš", "i", "imo", "ite", "e", "io", "jela", "jelo", "jeli", "eći",
+        ],
+    ),
+    (
+        "ovati",
+        &[
+            "ovati", "ujem", "uješ", "uje", "ujemo", "ujete", "uju", "ovao", "ovala", "ovalo",
+            "ovali", "ujući",
+        ],
+        &[
+            "ovati", "ujem", "uješ", "uje", "ujemo", "ujete", "uju", "ovao", "ovala", "ovalo",
+            "ovali", "ujući",
+        ],
+    ),
+];
+
+/// Returns `true` if the lowercase ijekavian word has `ije` (or `ије`) inside, which is usually
+/// the long yat (`rijeka`), rather than at its end (`Srbije`)
+fn has_long_yat(word: &str) -> bool {
+    ["ije", "ије"]
+        .iter()
+        .any(|long_yat| word.trim_end_matches(long_yat).contains(long_yat))
+}
+
+/// Forms of the words in the other pronunciation, keyed by their lowercase latin form
+#[derive(Clone, Debug)]
+pub(crate) struct DialectWords {
+    /// Ijekavian forms of ekavian words
+    ijekavian: CharTrie<Vec<String>>,
+    /// Ekavian forms of ijekavian words
+    ekavian: CharTrie<Vec<String>>,
+}
+
+impl DialectWords {
+    fn add(&mut self, ekavian: &str, ijekavian: &str) {
+        for (words, key, form) in [
+            (&mut self.ijekavian, ekavian, ijekavian),
+            (&mut self.ekavian, ijekavian, ekavian),
+        ] {
+            let forms = words.value_mut(key.chars()).get_or_insert_with(Vec::new);
+
+            if !forms.iter().any(|existing| existing == form) {
+                forms.push(form.to_string());
+            }
+        }
+    }
+
+    /// Returns forms of the lowercase word in the given pronunciation
+    fn forms(&self, word: &str, dialect: Dialect) -> Option<&Vec<String>> {
+        match dialect {
+            Dialect::Ekavian => self.ekavian.get(word.chars()),
+            Dialect::Ijekavian => self.ijekavian.get(word.chars()),
+        }
+    }
+}
+
+/// Returns the bundled word list
+fn bundled_words() -> &'static DialectWords {
+    static WORD_LIST: OnceLock<DialectWords> = OnceLock::new();
+
+    WORD_LIST.get_or_init(|| {
+        let mut words = DialectWords {
+            ijekavian: CharTrie::new(),
+            ekavian: CharTrie::new(),
+        };
+
+        let entries = WORDS
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('='));
+
+        for (ekavian, ijekavian) in entries {
+            let (ekavian, ijekavian) = (ekavian.trim(), ijekavian.trim());
+
+            let Some((stem, paradigm)) = ekavian.split_once('/') else {
+                words.add(ekavian, ijekavian);
+                continue;
+            };
+
+            let (_, ekavian_endings, ijekavian_endings) = PARADIGMS
+                .iter()
+                .find(|(name, _, _)| *name == paradigm)
+                .unwrap_or_else(|| panic!("unknown paradigm `{paradigm}` in the word list"));
+
+            for (ekavian_ending, ijekavian_ending) in
+                ekavian_endings.iter().zip(ijekavian_endings.iter())
+            {
+                words.add(
+                    &format!("{stem}{ekavian_ending}"),
+                    &format!("{ijekavian}{ijekavian_ending}"),
+                );
+            }
+        }
+
+        words
+    })
+}
+
+/// Compiles the bundled word list with the given pairs of ekavian and ijekavian words, which
+/// replace the bundled forms of the same words
+pub(crate) fn compile_dialect_words(pairs: &[(String, String)]) -> DialectWords {
+    let mut words = bundled_words().clone();
+
+    for (ekavian, ijekavian) in pairs {
+        let (ekavian, ijekavian) = (ekavian.to_lowercase(), ijekavian.to_lowercase());
+
+        *words.ijekavian.value_mut(ekavian.chars()) = Some(vec![ijekavian.clone()]);
+        *words.ekavian.value_mut(ijekavian.chars()) = Some(vec![ekavian]);
+    }
+
+    words
+}
+
+impl Converter {
+    /// Converts the text to the given pronunciation of yat (`mleko` to `mlijeko`, `dete` to
+    /// `dijete`, `videti` to `vidjeti` and back) by looking up its words in the bundled word list.
+    /// Both latin and cyrillic words are converted. Words which have several forms in the other
+    /// pronunciation (`svet` as `svijet` or `svet`) are kept and reported as ambiguous, and
+    /// ijekavian words which aren't in the list but have `ije` inside are reported as unknown.
+    pub fn convert_dialect(&self, input: &str, dialect: Dialect) -> ConversionReport {
+        let mut converted = String::with_capacity(input.len() + input.len() / 8);
+        let mut issues = vec![];
+
+        for token in tokenize(input) {
+            let text = &input[token.range.clone()];
+
+            if token.kind == TokenKind::Separator {
+                converted.push_str(text);
+                continue;
+            }
+
+            let kind = match self.dialect_forms(text, dialect) {
+                Some(mut forms) if forms.len() == 1 => {
+                    converted.push_str(&forms.remove(0));
+                    continue;
+                }
+                Some(forms) => IssueKind::AmbiguousWord(forms),
+                None if dialect == Dialect::Ekavian && has_long_yat(&text.to_lowercase()) => {
+                    IssueKind::UnknownWord
+                }
+                None => {
+                    converted.push_str(text);
+                    continue;
+                }
+            };
+
+            issues.push(Issue {
+                word: text.to_string(),
+                range: token.range,
+                kind,
+            });
+            converted.push_str(text);
+        }
+
+        ConversionReport {
+            text: converted,
+            issues,
+        }
+    }
+
+    /// Returns forms of the latin or cyrillic word in the given pronunciation, written in the
+    /// script and the case of the word
+    pub(super) fn dialect_forms(&self, word: &str, dialect: Dialect) -> Option<Vec<String>> {
+        let words = self
+            .dialect_words
+            .as_ref()
+            .unwrap_or_else(|| bundled_words());
+        let is_cyrillic = word
+            .chars()
+            .any(|c| Script::of(c) == Some(Script::Cyrillic));
+        let latin = if is_cyrillic {
+            Cow::Owned(self.cyr_to_lat_letters(word))
+        } else {
+            Cow::Borrowed(word)
+        };
+        let forms = words.forms(&latin.to_lowercase(), dialect)?;
+
+        let forms = forms
+            .iter()
+            .map(|form| {
+                let form = match_case(&latin, form);
+
+                if is_cyrillic {
+                    let mut cyrillic = String::with_capacity(form.len() * 2);
+                    self.lat_to_cyr_letters(&form, &mut cyrillic);
+                    cyrillic
+                } else {
+                    form
+                }
+            })
+            .collect();
+
+        Some(forms)
+    }
+}
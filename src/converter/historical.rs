@@ -0,0 +1,150 @@
+use super::scheme::match_case;
+use super::{Converter, Dialect};
+use crate::report::{ConversionReport, Issue, IssueKind};
+use crate::script::Script;
+use crate::tokenizer::{tokenize, TokenKind};
+
+/// Old lowercase letters which are always written the same way by the modern orthography
+const OLD_LETTERS: [(char, &str); 17] = [
+    ('ы', "и"),
+    ('і', "и"),
+    ('ї', "и"),
+    ('ѵ', "и"),
+    ('ѳ', "т"),
+    ('ѕ', "з"),
+    ('ѡ', "о"),
+    ('ѿ', "от"),
+    ('ѯ', "кс"),
+    ('ѱ', "пс"),
+    ('ѹ', "у"),
+    ('ꙋ', "у"),
+    ('є', "е"),
+    ('э', "е"),
+    ('й', "ј"),
+    ('щ', "шт"),
+    ('ꙗ', "ја"),
+];
+
+/// Returns the lowercase word written by the modern orthography, with yat written as `yat`.
+/// Only letters for which `is_old` returns `true` are changed. The hard sign and `й` after `и`
+/// are dropped, and the soft sign and `я`, `ю` soften the preceding `л`, `н`, `т` and `д`
+/// (`краль` to `краљ`, `любовь` to `љубов`, `братя` to `браћа`).
+fn modernize_word(word: &str, yat: &str, is_old: impl Fn(char) -> bool) -> String {
+    let mut modernized = String::with_capacity(word.len());
+
+    for c in word.chars().flat_map(char::to_lowercase) {
+        let softened = |previous| match previous {
+            'л' => Some('љ'),
+            'н' => Some('њ'),
+            'т' => Some('ћ'),
+            'д' => Some('ђ'),
+            _ => None,
+        };
+
+        if !is_old(c) {
+            modernized.push(c);
+            continue;
+        }
+
+        match c {
+            'ъ' => {}
+            // `-ій` and `-ый` of the Slavonic-Serbian adjectives (`сербскій`)
+            'й' if modernized.ends_with('и') => {}
+            'ь' => {
+                if let Some(soft) = modernized.chars().next_back().and_then(softened) {
+                    modernized.pop();
+                    modernized.push(soft);
+                }
+            }
+            'я' | 'ю' => {
+                match modernized.chars().next_back().and_then(softened) {
+                    Some(soft) => {
+                        modernized.pop();
+                        modernized.push(soft);
+                    }
+                    None => modernized.push('ј'),
+                }
+
+                modernized.push(if c == 'я' { 'а' } else { 'у' });
+            }
+            'ѣ' => modernized.push_str(yat),
+            _ => match OLD_LETTERS.iter().find(|(old, _)| *old == c) {
+                Some((_, modern)) => modernized.push_str(modern),
+                None => modernized.push(c),
+            },
+        }
+    }
+
+    modernized
+}
+
+impl Converter {
+    /// Returns `true` if the letter is cyrillic, but not a letter of the scheme's alphabet (so
+    /// `ѕ` is old in Serbian, but not in Macedonian)
+    fn is_old_letter(&self, c: char) -> bool {
+        Script::of(c) == Some(Script::Cyrillic)
+            && !c
+                .to_lowercase()
+                .all(|lowercase| self.cyrillic_letters.get([lowercase]).is_some())
+    }
+
+    /// Writes historical cyrillic text by the modern orthography: `ы`, `і` and `ѵ` as `и`, `ѳ`
+    /// as `т`, the hard sign `ъ` is dropped, and the Slavonic-Serbian `ль`, `нь`, `ть`, `дь`,
+    /// `я`, `ю` and `щ` are written as `љ`, `њ`, `ћ`, `ђ`, `ја`, `ју` and `шт`. Letters of the
+    /// scheme's alphabet are kept. Yat (`ѣ`) is written as `е` in ekavian, and by the ijekavian
+    /// form of the word from the word list of `convert_dialect` in ijekavian. Words whose yat
+    /// couldn't be resolved in ijekavian are written with `е` and reported, as are words which
+    /// still have old letters because the letter has no modern counterpart.
+    pub fn modernize_spelling(&self, input: &str, yat: Dialect) -> ConversionReport {
+        let mut modernized = String::with_capacity(input.len());
+        let mut issues = vec![];
+
+        for token in tokenize(input) {
+            let text = &input[token.range.clone()];
+
+            if token.kind == TokenKind::Separator || !text.chars().any(|c| self.is_old_letter(c)) {
+                modernized.push_str(text);
+                continue;
+            }
+
+            let ekavian = match_case(text, &modernize_word(text, "е", |c| self.is_old_letter(c)));
+            let has_yat = text.chars().any(|c| matches!(c, 'ѣ' | 'Ѣ'));
+            let mut is_resolved = true;
+
+            let word = match yat {
+                Dialect::Ijekavian if has_yat => {
+                    // yat is always written differently in ijekavian (`свѣт` as `свијет`, not as
+                    // `свет`)
+                    let forms = self
+                        .dialect_forms(&ekavian, Dialect::Ijekavian)
+                        .unwrap_or_default();
+                    let mut forms = forms.into_iter().filter(|form| *form != ekavian);
+
+                    match (forms.next(), forms.next()) {
+                        (Some(form), None) => form,
+                        _ => {
+                            is_resolved = false;
+                            ekavian
+                        }
+                    }
+                }
+                _ => ekavian,
+            };
+
+            if !is_resolved || word.chars().any(|c| self.is_old_letter(c)) {
+                issues.push(Issue {
+                    word: text.to_string(),
+                    range: token.range,
+                    kind: IssueKind::HistoricalSpelling,
+                });
+            }
+
+            modernized.push_str(&word);
+        }
+
+        ConversionReport {
+            text: modernized,
+            issues,
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use super::{Converter, Scheme};
+
+/// Filler character of the machine readable zone
+const FILLER: char = '<';
+
+/// Latin letters with diacritics which are written as the base letter
+const BASE_LETTERS: [(&str, char); 19] = [
+    ("ÀÁÂÃĀĂĄǍ", 'A'),
+    ("ÇĆĈĊČ", 'C'),
+    ("ĎÐ", 'D'),
+    ("ÈÉÊËĒĔĖĘĚ", 'E'),
+    ("ĜĞĠĢǴ", 'G'),
+    ("ĤĦ", 'H'),
+    ("ÌÍÎÏĨĪĬĮİ", 'I'),
+    ("Ĵ", 'J'),
+    ("ĶḰ", 'K'),
+    ("ĹĻĽĿŁ", 'L'),
+    ("ÑŃŅŇ", 'N'),
+    ("ÒÓÔÕŌŎŐ", 'O'),
+    ("ŔŖŘ", 'R'),
+    ("ŚŜŞŠ", 'S'),
+    ("ŢŤŦ", 'T'),
+    ("ÙÚÛŨŪŬŮŰŲ", 'U'),
+    ("Ŵ", 'W'),
+    ("ÝŶŸ", 'Y'),
+    ("ŹŻŽ", 'Z'),
+];
+
+/// Transliteration of cyrillic letters recommended by ICAO Doc 9303, part 3
+fn cyr_letter_to_icao(letter: char) -> Option<&'static str> {
+    match letter {
+        'А' => Some("A"),
+        'Б' => Some("B"),
+        'В' => Some("V"),
+        'Г' | 'Ѓ' | 'Ґ' => Some("G"),
+        'Д' | 'Ђ' => Some("D"),
+        'Е' | 'Ё' | 'Э' => Some("E"),
+        'Є' | 'Ъ' => Some("IE"),
+        'Ж' => Some("ZH"),
+        'З' => Some("Z"),
+        'Ѕ' | 'Џ' => Some("DZ"),
+        'И' | 'І' | 'Ї' | 'Й' => Some("I"),
+        'Ј' => Some("J"),
+        'К' | 'Ќ' => Some("K"),
+        'Л' => Some("L"),
+        'Љ' => Some("LJ"),
+        'М' => Some("M"),
+        'Н' => Some("N"),
+        'Њ' => Some("NJ"),
+        'О' => Some("O"),
+        'П' => Some("P"),
+        'Р' => Some("R"),
+        'С' => Some("S"),
+        'Т' => Some("T"),
+        'Ћ' => Some("C"),
+        'У' | 'Ў' => Some("U"),
+        'Ф' => Some("F"),
+        'Х' => Some("KH"),
+        'Ц' => Some("TS"),
+        'Ч' => Some("CH"),
+        'Ш' => Some("SH"),
+        'Щ' => Some("SHCH"),
+        'Ы' => Some("Y"),
+        'Ь' => Some(""),
+        'Ю' => Some("IU"),
+        'Я' => Some("IA"),
+        _ => None,
+    }
+}
+
+/// Returns the latin counterpart of the lowercase cyrillic letter in the alphabets of the scheme
+fn cyr_letter_to_lat(scheme: Scheme, letter: char) -> Option<&'static str> {
+    scheme
+        .table()
+        .letters
+        .iter()
+        .find(|(cyrillic, _, _)| cyrillic.chars().eq([letter]))
+        .map(|&(_, latin, _)| latin)
+}
+
+/// Transliteration of uppercase latin letters which aren't written as their base letter
+fn lat_letter_to_icao(letter: char) -> Option<&'static str> {
+    match letter {
+        'Đ' => Some("DJ"),
+        'Ä' | 'Æ' => Some("AE"),
+        'Å' => Some("AA"),
+        'Ö' | 'Ø' | 'Œ' => Some("OE"),
+        'Ü' => Some("UE"),
+        'ß' | 'ẞ' => Some("SS"),
+        'Þ' => Some("TH"),
+        'Ĳ' => Some("IJ"),
+        _ => None,
+    }
+}
+
+/// Writes the uppercase latin letter without diacritics, omitting other characters
+fn push_lat_letter(letter: char, converted: &mut String) {
+    if letter.is_ascii_alphanumeric() {
+        converted.push(letter);
+    } else if let Some(letters) = lat_letter_to_icao(letter) {
+        converted.push_str(letters);
+    } else if let Some(&(_, base)) = BASE_LETTERS
+        .iter()
+        .find(|(letters, _)| letters.contains(letter))
+    {
+        converted.push(base);
+    }
+}
+
+impl Converter {
+    /// Converts the name to characters allowed in the machine readable zone of travel documents
+    /// (ICAO Doc 9303): uppercase ASCII letters, digits and `<` in place of spaces and hyphens.
+    /// Cyrillic letters of Serbian and Montenegrin schemes are converted to Gaj's alphabet, and
+    /// then their diacritics are removed (`Ђорђе` to `DJORDJE`, `Жарко` to `ZARKO`), with the
+    /// reversible scheme using the Serbian alphabet. Other cyrillic letters, and all of them in
+    /// other schemes, are converted by the ICAO table. Diacritics of latin letters are removed,
+    /// and other characters are omitted.
+    pub fn to_icao(&self, name: &str) -> String {
+        let gaj_scheme = match self.scheme {
+            Scheme::Serbian | Scheme::SerbianReversible => Some(Scheme::Serbian),
+            Scheme::Montenegrin => Some(Scheme::Montenegrin),
+            _ => None,
+        };
+        let mut converted = String::with_capacity(name.len());
+
+        for c in name.chars().flat_map(char::to_uppercase) {
+            let lowercase = c.to_lowercase().next().unwrap_or(c);
+
+            if c.is_whitespace() || ['-', '\u{2010}', '\u{2011}'].contains(&c) {
+                if !converted.is_empty() && !converted.ends_with(FILLER) {
+                    converted.push(FILLER);
+                }
+            } else if let Some(latin) =
+                gaj_scheme.and_then(|scheme| cyr_letter_to_lat(scheme, lowercase))
+            {
+                for letter in latin.chars().flat_map(char::to_uppercase) {
+                    push_lat_letter(letter, &mut converted);
+                }
+            } else if let Some(letters) = cyr_letter_to_icao(c) {
+                converted.push_str(letters);
+            } else {
+                // combining marks, apostrophes, dots and other characters are omitted
+                push_lat_letter(c, &mut converted);
+            }
+        }
+
+        while converted.ends_with(FILLER) {
+            converted.pop();
+        }
+
+        converted
+    }
+
+    /// Formats the name field of the machine readable zone: the primary identifier (surname)
+    /// and the secondary identifier (given names) separated by `<<`, padded with `<` or
+    /// truncated to `width` characters
+    pub fn to_mrz_name(&self, primary: &str, secondary: &str, width: usize) -> String {
+        let mut field = self.to_icao(primary);
+        let secondary = self.to_icao(secondary);
+
+        field.push(FILLER);
+        field.push(FILLER);
+        field.push_str(&secondary);
+        field.truncate(width);
+
+        while field.len() < width {
+            field.push(FILLER);
+        }
+
+        field
+    }
+}
@@ -0,0 +1,184 @@
+use std::sync::OnceLock;
+
+use super::automaton::CharTrie;
+use super::scheme::{compile_rules, match_case, Context, Conversion};
+use super::{Converter, SourceLanguage};
+use crate::tokenizer::{tokenize, TokenKind};
+
+mod english;
+mod french;
+mod german;
+mod italian;
+
+/// Bundled lexicon of foreign names, the format is described in the file itself
+const NAMES: &str = include_str!("../../data/foreign_names.txt");
+
+/// Cyrillic letters which are written twice when they are doubled in the name
+const VOWELS: &str = "аеиоу";
+
+impl SourceLanguage {
+    fn from_tag(tag: &str) -> Option<SourceLanguage> {
+        match tag {
+            "en" => Some(SourceLanguage::English),
+            "de" => Some(SourceLanguage::German),
+            "fr" => Some(SourceLanguage::French),
+            "it" => Some(SourceLanguage::Italian),
+            _ => None,
+        }
+    }
+
+    /// Returns compiled transcription rules of the language
+    fn rules(self) -> &'static CharTrie<Conversion> {
+        static ENGLISH: OnceLock<CharTrie<Conversion>> = OnceLock::new();
+        static GERMAN: OnceLock<CharTrie<Conversion>> = OnceLock::new();
+        static FRENCH: OnceLock<CharTrie<Conversion>> = OnceLock::new();
+        static ITALIAN: OnceLock<CharTrie<Conversion>> = OnceLock::new();
+
+        let (compiled, rules): (_, &[(&str, &str, Context)]) = match self {
+            SourceLanguage::English => (&ENGLISH, &english::RULES),
+            SourceLanguage::German => (&GERMAN, &german::RULES),
+            SourceLanguage::French => (&FRENCH, &french::RULES),
+            SourceLanguage::Italian => (&ITALIAN, &italian::RULES),
+        };
+
+        compiled.get_or_init(|| compile_rules(rules))
+    }
+
+    /// Transcribes a single word by the rules of the language. Doubled consonants are written
+    /// once (`Schiller` to `Шилер`).
+    fn transcribe(self, word: &str) -> String {
+        let lowercase: String = word
+            .chars()
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+            .collect();
+        let rules = self.rules();
+        let mut transcribed = String::with_capacity(lowercase.len() * 2);
+        let mut previous = None;
+        let mut rest = lowercase.as_str();
+
+        while let Some(c) = rest.chars().next() {
+            let mut resolved = None;
+            let matched = rules.longest_match_in(rest, |len, conversion| {
+                match conversion.resolve(previous, || rest[len..].chars().next()) {
+                    Some(cyrillic) => {
+                        resolved = Some(cyrillic);
+                        true
+                    }
+                    None => false,
+                }
+            });
+            let byte_len = matched.map_or(c.len_utf8(), |(len, _)| len);
+            let (current, next_rest) = rest.split_at(byte_len);
+
+            for letter in resolved.unwrap_or(current).chars() {
+                if !transcribed.ends_with(letter) || VOWELS.contains(letter) {
+                    transcribed.push(letter);
+                }
+            }
+
+            previous = current.chars().next_back();
+            rest = next_rest;
+        }
+
+        match_case(word, &transcribed)
+    }
+}
+
+/// Returns the bundled lexicon, keyed by lowercase names
+fn bundled_lexicon() -> &'static CharTrie<String> {
+    static LEXICON: OnceLock<CharTrie<String>> = OnceLock::new();
+
+    LEXICON.get_or_init(|| {
+        let mut lexicon = CharTrie::new();
+        let mut language = None;
+
+        for line in NAMES.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(tag) = line
+                .strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'))
+            {
+                language = SourceLanguage::from_tag(tag);
+                continue;
+            }
+
+            let (name, transcription) = match line.split_once('=') {
+                Some((name, transcription)) => (name.trim(), transcription.trim().to_string()),
+                None => match language {
+                    Some(language) => (line, language.transcribe(line)),
+                    None => continue,
+                },
+            };
+
+            lexicon
+                .value_mut(name.chars().flat_map(char::to_lowercase))
+                .get_or_insert(transcription);
+        }
+
+        lexicon
+    })
+}
+
+/// Compiles the bundled lexicon with the given names, which replace the bundled ones
+pub(crate) fn compile_lexicon(
+    transcriptions: &[(String, String)],
+    names: &[(SourceLanguage, String)],
+) -> CharTrie<String> {
+    let mut lexicon = bundled_lexicon().clone();
+    let transcribed = names
+        .iter()
+        .map(|(language, name)| (name.as_str(), language.transcribe(name)));
+    let entries = transcriptions
+        .iter()
+        .map(|(name, transcription)| (name.as_str(), transcription.clone()))
+        .chain(transcribed);
+
+    for (name, transcription) in entries {
+        *lexicon.value_mut(name.chars().flat_map(char::to_lowercase)) = Some(transcription);
+    }
+
+    lexicon
+}
+
+fn look_up(lexicon: &CharTrie<String>, name: &str) -> Option<String> {
+    lexicon
+        .get(name.chars().flat_map(char::to_lowercase))
+        .map(|transcription| match_case(name, transcription))
+}
+
+impl Converter {
+    /// Transcribes the foreign name to cyrillic by the Serbian orthography (`Shakespeare` to
+    /// `Шекспир`, `Goethe` to `Гете`). Each word of the name is looked up in the lexicon of
+    /// names, and transcribed by the rules of the given language if it isn't there.
+    pub fn transcribe_name(&self, name: &str, language: SourceLanguage) -> String {
+        let lexicon = self.names.as_ref().unwrap_or_else(|| bundled_lexicon());
+        let mut transcribed = String::with_capacity(name.len() * 2);
+
+        for token in tokenize(name) {
+            let text = &name[token.range];
+
+            match token.kind {
+                TokenKind::Separator => transcribed.push_str(text),
+                TokenKind::Word => transcribed
+                    .push_str(&look_up(lexicon, text).unwrap_or_else(|| language.transcribe(text))),
+            }
+        }
+
+        transcribed
+    }
+
+    /// Returns transcription of the capitalised word if name transcription is enabled and the
+    /// word is in the lexicon
+    pub(crate) fn lexicon_name(&self, word: &str) -> Option<String> {
+        let lexicon = self.names.as_ref()?;
+
+        if word.chars().next().is_some_and(char::is_uppercase) {
+            look_up(lexicon, word)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::converter::scheme::Context::{self, After, Anywhere, Before, WordEnd, WordStart};
+
+const VOWELS: &str = "aeiouy";
+
+pub(super) static RULES: [(&str, &str, Context); 54] = [
+    ("tch", "ч", Anywhere),
+    ("sh", "ш", Anywhere),
+    ("ch", "ч", Anywhere),
+    ("th", "т", Anywhere),
+    ("ph", "ф", Anywhere),
+    ("wh", "в", Anywhere),
+    ("ck", "к", Anywhere),
+    ("qu", "кв", Anywhere),
+    ("kn", "н", WordStart),
+    ("wr", "р", WordStart),
+    ("tion", "шн", Anywhere),
+    ("igh", "ај", Anywhere),
+    ("ee", "и", Anywhere),
+    ("ea", "и", Anywhere),
+    ("oo", "у", Anywhere),
+    ("ou", "ау", Anywhere),
+    ("ow", "оу", WordEnd),
+    ("ow", "ау", Anywhere),
+    ("ay", "еј", Anywhere),
+    ("ai", "еј", Anywhere),
+    ("ey", "и", WordEnd),
+    ("ey", "еј", Anywhere),
+    ("oy", "ој", Anywhere),
+    ("a", "а", Anywhere),
+    ("b", "б", Anywhere),
+    ("c", "с", Before("eiy")),
+    ("c", "к", Anywhere),
+    ("d", "д", Anywhere),
+    ("e", "", WordEnd),
+    ("e", "е", Anywhere),
+    ("f", "ф", Anywhere),
+    ("g", "џ", Before("e")),
+    ("g", "г", Anywhere),
+    ("h", "", After(VOWELS)),
+    ("h", "х", Anywhere),
+    ("i", "и", Anywhere),
+    ("j", "џ", Anywhere),
+    ("k", "к", Anywhere),
+    ("l", "л", Anywhere),
+    ("m", "м", Anywhere),
+    ("n", "н", Anywhere),
+    ("o", "о", Anywhere),
+    ("p", "п", Anywhere),
+    ("r", "р", Anywhere),
+    ("s", "с", Anywhere),
+    ("t", "т", Anywhere),
+    ("u", "у", Anywhere),
+    ("v", "в", Anywhere),
+    ("w", "в", Anywhere),
+    ("x", "кс", Anywhere),
+    ("y", "ј", Before(VOWELS)),
+    ("y", "и", Anywhere),
+    ("z", "з", Anywhere),
+    ("q", "к", Anywhere),
+];
@@ -0,0 +1,86 @@
+use crate::converter::scheme::Context::{self, After, Anywhere, Before, WordEnd, WordStart};
+
+const VOWELS: &str = "aeiouyéèêëàâîïôûù";
+const CONSONANTS: &str = "bcçdfghjklmnpqrstvwxz";
+
+pub(super) static RULES: [(&str, &str, Context); 79] = [
+    ("eaux", "о", WordEnd),
+    ("eau", "о", Anywhere),
+    ("aux", "о", WordEnd),
+    ("au", "о", Anywhere),
+    ("ou", "у", Anywhere),
+    ("oi", "оа", Anywhere),
+    ("ch", "ш", Anywhere),
+    ("gn", "њ", Anywhere),
+    ("qu", "к", Anywhere),
+    ("ph", "ф", Anywhere),
+    ("th", "т", Anywhere),
+    ("ss", "с", Anywhere),
+    ("ean", "ан", WordEnd),
+    ("ain", "ен", WordEnd),
+    ("ain", "ен", Before(CONSONANTS)),
+    ("ein", "ен", WordEnd),
+    ("ein", "ен", Before(CONSONANTS)),
+    ("in", "ен", WordEnd),
+    ("in", "ен", Before(CONSONANTS)),
+    ("an", "ан", WordEnd),
+    ("an", "ан", Before(CONSONANTS)),
+    ("en", "ан", WordEnd),
+    ("en", "ан", Before(CONSONANTS)),
+    ("un", "ен", WordEnd),
+    ("un", "ен", Before(CONSONANTS)),
+    ("eu", "е", Anywhere),
+    ("œu", "е", Anywhere),
+    ("er", "е", WordEnd),
+    ("ez", "е", WordEnd),
+    ("gu", "г", Before("eiy")),
+    ("a", "а", Anywhere),
+    ("à", "а", Anywhere),
+    ("â", "а", Anywhere),
+    ("b", "б", Anywhere),
+    ("c", "с", Before("eiy")),
+    ("c", "к", Anywhere),
+    ("ç", "с", Anywhere),
+    ("d", "", WordEnd),
+    ("d", "д", Anywhere),
+    ("e", "", WordEnd),
+    ("e", "е", Anywhere),
+    ("é", "е", Anywhere),
+    ("è", "е", Anywhere),
+    ("ê", "е", Anywhere),
+    ("ë", "е", Anywhere),
+    ("f", "ф", Anywhere),
+    ("g", "ж", Before("eiy")),
+    ("g", "г", Anywhere),
+    ("h", "", Anywhere),
+    ("i", "и", Anywhere),
+    ("î", "и", Anywhere),
+    ("ï", "и", Anywhere),
+    ("j", "ж", Anywhere),
+    ("k", "к", Anywhere),
+    ("l", "л", Anywhere),
+    ("m", "м", Anywhere),
+    ("n", "н", Anywhere),
+    ("o", "о", Anywhere),
+    ("ô", "о", Anywhere),
+    ("p", "", WordEnd),
+    ("p", "п", Anywhere),
+    ("r", "р", Anywhere),
+    ("s", "с", WordStart),
+    ("s", "", WordEnd),
+    ("s", "с", After(CONSONANTS)),
+    ("s", "з", Before(VOWELS)),
+    ("s", "с", Anywhere),
+    ("t", "", WordEnd),
+    ("t", "т", Anywhere),
+    ("u", "и", Anywhere),
+    ("û", "и", Anywhere),
+    ("ù", "у", Anywhere),
+    ("v", "в", Anywhere),
+    ("w", "в", Anywhere),
+    ("x", "", WordEnd),
+    ("x", "кс", Anywhere),
+    ("y", "и", Anywhere),
+    ("z", "", WordEnd),
+    ("z", "з", Anywhere),
+];
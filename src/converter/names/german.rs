@@ -0,0 +1,63 @@
+use crate::converter::scheme::Context::{self, After, Anywhere, Before, WordStart};
+
+const VOWELS: &str = "aeiouyäöü";
+
+pub(super) static RULES: [(&str, &str, Context); 57] = [
+    ("tsch", "ч", Anywhere),
+    ("sch", "ш", Anywhere),
+    ("chs", "кс", Anywhere),
+    ("ch", "х", Anywhere),
+    ("sp", "шп", WordStart),
+    ("st", "шт", WordStart),
+    ("ck", "к", Anywhere),
+    ("tz", "ц", Anywhere),
+    ("dt", "т", Anywhere),
+    ("th", "т", Anywhere),
+    ("ph", "ф", Anywhere),
+    ("qu", "кв", Anywhere),
+    ("ss", "с", Anywhere),
+    ("ei", "ај", Anywhere),
+    ("ey", "ај", Anywhere),
+    ("ai", "ај", Anywhere),
+    ("eu", "ој", Anywhere),
+    ("äu", "ој", Anywhere),
+    ("ie", "и", Anywhere),
+    ("ae", "е", Anywhere),
+    ("oe", "е", Anywhere),
+    ("ue", "и", Anywhere),
+    ("aa", "а", Anywhere),
+    ("ee", "е", Anywhere),
+    ("oo", "о", Anywhere),
+    ("a", "а", Anywhere),
+    ("ä", "е", Anywhere),
+    ("b", "б", Anywhere),
+    ("c", "ц", Before("eiyäö")),
+    ("c", "к", Anywhere),
+    ("d", "д", Anywhere),
+    ("e", "е", Anywhere),
+    ("f", "ф", Anywhere),
+    ("g", "г", Anywhere),
+    ("h", "", After(VOWELS)),
+    ("h", "х", Anywhere),
+    ("i", "и", Anywhere),
+    ("j", "ј", Anywhere),
+    ("k", "к", Anywhere),
+    ("l", "л", Anywhere),
+    ("m", "м", Anywhere),
+    ("n", "н", Anywhere),
+    ("o", "о", Anywhere),
+    ("ö", "е", Anywhere),
+    ("p", "п", Anywhere),
+    ("r", "р", Anywhere),
+    ("s", "з", Before(VOWELS)),
+    ("s", "с", Anywhere),
+    ("ß", "с", Anywhere),
+    ("t", "т", Anywhere),
+    ("u", "у", Anywhere),
+    ("ü", "и", Anywhere),
+    ("v", "ф", Anywhere),
+    ("w", "в", Anywhere),
+    ("x", "кс", Anywhere),
+    ("y", "и", Anywhere),
+    ("z", "ц", Anywhere),
+];
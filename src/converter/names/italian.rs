@@ -0,0 +1,56 @@
+use crate::converter::scheme::Context::{self, Anywhere, Before};
+
+const VOWELS: &str = "aeiouàèéìòù";
+
+pub(super) static RULES: [(&str, &str, Context); 50] = [
+    ("sci", "ш", Before(VOWELS)),
+    ("sc", "ш", Before("eiéèì")),
+    ("sch", "ск", Anywhere),
+    ("cci", "ч", Before(VOWELS)),
+    ("cc", "ч", Before("eiéèì")),
+    ("cch", "к", Anywhere),
+    ("ci", "ч", Before(VOWELS)),
+    ("ch", "к", Anywhere),
+    ("ggi", "ђ", Before(VOWELS)),
+    ("gg", "ђ", Before("eiéèì")),
+    ("gi", "ђ", Before(VOWELS)),
+    ("gli", "љ", Before(VOWELS)),
+    ("gli", "љи", Anywhere),
+    ("gh", "г", Anywhere),
+    ("gn", "њ", Anywhere),
+    ("qu", "кв", Anywhere),
+    ("a", "а", Anywhere),
+    ("à", "а", Anywhere),
+    ("b", "б", Anywhere),
+    ("c", "ч", Before("eiéèì")),
+    ("c", "к", Anywhere),
+    ("d", "д", Anywhere),
+    ("e", "е", Anywhere),
+    ("è", "е", Anywhere),
+    ("é", "е", Anywhere),
+    ("f", "ф", Anywhere),
+    ("g", "ђ", Before("eiéèì")),
+    ("g", "г", Anywhere),
+    ("h", "", Anywhere),
+    ("i", "и", Anywhere),
+    ("ì", "и", Anywhere),
+    ("j", "ј", Anywhere),
+    ("k", "к", Anywhere),
+    ("l", "л", Anywhere),
+    ("m", "м", Anywhere),
+    ("n", "н", Anywhere),
+    ("o", "о", Anywhere),
+    ("ò", "о", Anywhere),
+    ("p", "п", Anywhere),
+    ("r", "р", Anywhere),
+    ("s", "с", Anywhere),
+    ("t", "т", Anywhere),
+    ("u", "у", Anywhere),
+    ("ù", "у", Anywhere),
+    ("v", "в", Anywhere),
+    ("w", "в", Anywhere),
+    ("x", "кс", Anywhere),
+    ("y", "и", Anywhere),
+    ("z", "ц", Anywhere),
+    ("q", "к", Anywhere),
+];
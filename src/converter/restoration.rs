@@ -0,0 +1,226 @@
+use std::borrow::Cow;
+
+use super::automaton::CharTrie;
+use super::scheme::match_case;
+use super::RestorationFallback;
+use crate::report::{Issue, IssueKind};
+
+/// Bundled list of Serbian words with diacritics, the format is described in the file itself
+const WORDS: &str = include_str!("../../data/serbian_words.txt");
+
+/// Endings of the paradigms used in the word list
+const PARADIGMS: [(&str, &[&str]); 9] = [
+    ("f", &["a", "e", "i", "u", "o", "om", "ama"]),
+    ("fi", &["", "i", "u", "ju", "ima"]),
+    (
+        "m",
+        &[
+            "", "a", "u", "om", "em", "e", "i", "ima", "evi", "eva", "evima",
+        ],
+    ),
+    ("n", &["o", "a", "u", "om", "ima"]),
+    ("ne", &["e", "a", "u", "em", "ima"]),
+    (
+        "adj",
+        &[
+            "", "a", "o", "e", "i", "u", "og", "oga", "oj", "om", "ome", "im", "ih", "ima", "ega",
+            "em", "emu",
+        ],
+    ),
+    (
+        "ati",
+        &[
+            "ati", "am", "aš", "a", "amo", "ate", "aju", "ao", "ala", "alo", "ali", "ajući",
+        ],
+    ),
+    (
+        "iti",
+        &[
+            "iti", "im", "iš", "i", "imo", "ite", "e", "io", "ila", "ilo", "ili", "eći",
+        ],
+    ),
+    (
+        "eti",
+        &[
+            "eti", "im", "iš", "i", "imo", "ite", "e", "eo", "ela", "elo", "eli", "eći",
+        ],
+    ),
+];
+
+/// Expands an entry of the word list (`word` or `stem/paradigm`) into its forms
+fn forms(entry: &str) -> Vec<String> {
+    match entry.split_once('/') {
+        Some((stem, paradigm)) => {
+            let (_, endings) = PARADIGMS
+                .iter()
+                .find(|(name, _)| *name == paradigm)
+                .unwrap_or_else(|| panic!("unknown paradigm `{paradigm}` in the word list"));
+
+            endings
+                .iter()
+                .map(|ending| format!("{stem}{ending}"))
+                .collect()
+        }
+        None => vec![entry.to_string()],
+    }
+}
+
+/// Returns lowercase form of the word without diacritics (`Đačić` to `djacic`)
+fn strip_diacritics(word: &str) -> String {
+    let mut stripped = String::with_capacity(word.len());
+
+    for c in word.chars().flat_map(char::to_lowercase) {
+        match c {
+            'č' | 'ć' => stripped.push('c'),
+            'š' => stripped.push('s'),
+            'ž' => stripped.push('z'),
+            'đ' => stripped.push_str("dj"),
+            _ => stripped.push(c),
+        }
+    }
+
+    stripped
+}
+
+fn has_diacritics(word: &str) -> bool {
+    word.chars()
+        .any(|c| matches!(c, 'č' | 'ć' | 'š' | 'ž' | 'đ' | 'Č' | 'Ć' | 'Š' | 'Ž' | 'Đ'))
+}
+
+/// Returns `true` if the lowercase word has letters which may be written with diacritics
+fn may_need_diacritics(word: &str) -> bool {
+    word.contains(['c', 's', 'z']) || word.contains("dj")
+}
+
+/// Restores diacritics of latin words written without them (`zivot` to `život`) by looking
+/// them up in the word list, keyed by their lowercase form without diacritics
+#[derive(Clone, Debug)]
+pub(crate) struct Restorer {
+    /// Forms of the words, the most common one first
+    words: CharTrie<Vec<String>>,
+    /// Endings restored on words which aren't in the list, keyed by their reversed form
+    endings: CharTrie<String>,
+    fallback: RestorationFallback,
+}
+
+impl Restorer {
+    /// Compiles the bundled word list, with the given words added before it
+    pub(crate) fn new(words: &[&str], fallback: RestorationFallback) -> Restorer {
+        let mut restorer = Restorer {
+            words: CharTrie::new(),
+            endings: CharTrie::new(),
+            fallback,
+        };
+
+        for word in words {
+            restorer.add_word(word);
+        }
+
+        let entries = WORDS
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+        for entry in entries {
+            match entry.strip_prefix('-') {
+                Some(ending) => {
+                    for form in forms(ending) {
+                        let key = strip_diacritics(&form);
+                        restorer
+                            .endings
+                            .value_mut(key.chars().rev())
+                            .get_or_insert(form);
+                    }
+                }
+                None => {
+                    for form in forms(entry) {
+                        restorer.add_word(&form);
+                    }
+                }
+            }
+        }
+
+        restorer
+    }
+
+    fn add_word(&mut self, word: &str) {
+        let forms = self
+            .words
+            .value_mut(strip_diacritics(word).chars())
+            .get_or_insert_with(Vec::new);
+
+        if !forms.iter().any(|form| form == word) {
+            forms.push(word.to_string());
+        }
+    }
+
+    /// Returns the word with restored diacritics. Words which already have diacritics are left
+    /// unchanged. Words which aren't in the list, or have several forms in it, are reported as
+    /// issues at the byte range starting at `start`.
+    pub(crate) fn restore<'a>(
+        &self,
+        word: &'a str,
+        start: usize,
+        issues: &mut Vec<Issue>,
+    ) -> Cow<'a, str> {
+        if has_diacritics(word) {
+            return Cow::Borrowed(word);
+        }
+
+        let lowercase: String = word
+            .chars()
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+            .collect();
+
+        if !may_need_diacritics(&lowercase) {
+            return Cow::Borrowed(word);
+        }
+
+        let mut report = |kind| {
+            issues.push(Issue {
+                word: word.to_string(),
+                range: start..start + word.len(),
+                kind,
+            })
+        };
+
+        if let Some(forms) = self.words.get(lowercase.chars()) {
+            if forms.len() > 1 {
+                report(IssueKind::AmbiguousWord(forms.clone()));
+
+                if self.fallback == RestorationFallback::Keep {
+                    return Cow::Borrowed(word);
+                }
+            }
+
+            return Cow::Owned(match_case(word, &forms[0]));
+        }
+
+        // the ending alone isn't a word, so some other letters have to precede it
+        let len = lowercase.chars().count();
+        let ending = self
+            .endings
+            .longest_match_by(lowercase.chars().rev(), |ending_len, _| ending_len < len);
+
+        match ending {
+            Some((ending_len, ending)) => {
+                let rest_len = len - ending_len;
+                let split = word
+                    .char_indices()
+                    .nth(rest_len)
+                    .map_or(word.len(), |(i, _)| i);
+                let (rest, typed_ending) = word.split_at(split);
+
+                if may_need_diacritics(&lowercase.chars().take(rest_len).collect::<String>()) {
+                    report(IssueKind::UnknownWord);
+                }
+
+                Cow::Owned(format!("{rest}{}", match_case(typed_ending, ending)))
+            }
+            None => {
+                report(IssueKind::UnknownWord);
+                Cow::Borrowed(word)
+            }
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use super::automaton::CharTrie;
+use super::Scheme;
+
+mod bulgarian;
+mod macedonian;
+mod montenegrin;
+mod russian;
+mod serbian;
+mod serbian_reversible;
+mod ukrainian;
+
+/// Decides when a latin sequence of the letter table may be converted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LetterKind {
+    /// Always converted
+    Plain,
+    /// Converted unless the word starts with a prefix from the dictionary
+    Guarded,
+    /// Like `Guarded`, but only if `dj` conversion is enabled
+    Dj,
+    /// Like `Guarded`, but only if `dz` conversion is enabled
+    Dz,
+    /// Like `Guarded`, but only if English digraphs (`sh`, `ch`, `zh`, `cj`, `tj`) are enabled
+    English,
+    /// Letter which isn't in the alphabet (e.g. `x`), only converted if its transcription is
+    /// enabled
+    Foreign,
+}
+
+/// Position of a sequence in the word which a contextual rule requires
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Context {
+    /// Anywhere, used for sequences which only exist as a rule (e.g. `тс`)
+    Anywhere,
+    /// At the start of a word
+    WordStart,
+    /// At the end of a word
+    WordEnd,
+    /// After one of the given lowercase letters
+    After(&'static str),
+    /// Before one of the given lowercase letters
+    Before(&'static str),
+}
+
+impl Context {
+    fn matches(self, previous: Option<char>, next: Option<char>) -> bool {
+        let previous = previous.filter(|c| c.is_alphabetic());
+        let next = next.filter(|c| c.is_alphabetic());
+        let is_in = |letters: &str, c: Option<char>| {
+            c.is_some_and(|c| c.to_lowercase().all(|c| letters.contains(c)))
+        };
+
+        match self {
+            Context::Anywhere => true,
+            Context::WordStart => previous.is_none(),
+            Context::WordEnd => next.is_none(),
+            Context::After(letters) => is_in(letters, previous),
+            Context::Before(letters) => is_in(letters, next),
+        }
+    }
+}
+
+/// Case of the sequence in the compiled table
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LetterCase {
+    Lower,
+    /// First letter is uppercase. A single uppercase letter is in title case as well.
+    Title,
+    Upper,
+}
+
+impl LetterCase {
+    fn apply(self, text: &str) -> String {
+        match self {
+            LetterCase::Lower => text.to_string(),
+            LetterCase::Title => title_case(text),
+            LetterCase::Upper => text.to_uppercase(),
+        }
+    }
+
+    /// Returns lowercase, title case and uppercase variant of the lowercase text, skipping those
+    /// which are equal to the previous one
+    fn variants(text: &str) -> Vec<(LetterCase, String)> {
+        let mut variants: Vec<(LetterCase, String)> = vec![];
+
+        for case in [LetterCase::Lower, LetterCase::Title, LetterCase::Upper] {
+            let variant = case.apply(text);
+
+            if variants.iter().all(|(_, existing)| *existing != variant) {
+                variants.push((case, variant));
+            }
+        }
+
+        variants
+    }
+}
+
+/// Converted form of the sequence, which may depend on the neighbouring letters
+#[derive(Clone, Debug)]
+pub(crate) struct Conversion {
+    default: Option<String>,
+    rules: Vec<(Context, String)>,
+}
+
+impl Conversion {
+    /// Returns the converted form for the given neighbouring characters. The next character is
+    /// looked up only if there are contextual rules.
+    pub(crate) fn resolve<F>(&self, previous: Option<char>, next: F) -> Option<&str>
+    where
+        F: FnOnce() -> Option<char>,
+    {
+        if self.rules.is_empty() {
+            return self.default.as_deref();
+        }
+
+        let next = next();
+
+        self.rules
+            .iter()
+            .find(|(context, _)| context.matches(previous, next))
+            .map(|(_, converted)| converted.as_str())
+            .or(self.default.as_deref())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct LatinLetter {
+    pub(crate) cyrillic: Conversion,
+    pub(crate) kind: LetterKind,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct CyrillicLetter {
+    pub(crate) latin: Conversion,
+    pub(crate) case: LetterCase,
+}
+
+/// Letter tables of the scheme. Letters are written in lowercase, uppercase and title case
+/// variants are generated when the tables are compiled. If several letters share the latin
+/// sequence, the first one is used when converting to cyrillic.
+pub(crate) struct SchemeTable {
+    /// Cyrillic letters with their latin counterparts, used in both directions
+    pub(crate) letters: &'static [(&'static str, &'static str, LetterKind)],
+    /// Latin sequences which are only read when converting to cyrillic
+    pub(crate) latin_alternatives: &'static [(&'static str, &'static str, LetterKind)],
+    /// Cyrillic sequences which are converted differently in the given context
+    pub(crate) cyrillic_rules: &'static [(&'static str, &'static str, Context)],
+    /// Latin sequences which are converted differently in the given context
+    pub(crate) latin_rules: &'static [(&'static str, &'static str, Context)],
+    /// Transcription of latin letters which aren't in the alphabet, by the first matching context
+    pub(crate) foreign_letters: &'static [(&'static str, &'static str, Context)],
+    /// Lowercase sequences of letters which don't exist in words of the language
+    pub(crate) impossible_sequences: &'static [&'static str],
+    /// Prefixes of words whose guarded digraphs are kept literal
+    pub(crate) literal_prefixes: &'static [&'static str],
+    /// Primary language subtags of the languages written in this scheme
+    pub(crate) languages: &'static [&'static str],
+    /// Character which marks the following character as literal. If set, latin characters of
+    /// the input are escaped when converting to latin, and escaped characters are copied as they
+    /// are when converting to cyrillic.
+    pub(crate) escape: Option<char>,
+}
+
+impl Scheme {
+    pub(crate) fn table(self) -> &'static SchemeTable {
+        match self {
+            Scheme::Serbian => &serbian::TABLE,
+            Scheme::Macedonian => &macedonian::TABLE,
+            Scheme::Montenegrin => &montenegrin::TABLE,
+            Scheme::RussianIso9 => &russian::ISO_9,
+            Scheme::RussianGost779B => &russian::GOST_7_79_B,
+            Scheme::RussianBgnPcgn => &russian::BGN_PCGN,
+            Scheme::Ukrainian => &ukrainian::TABLE,
+            Scheme::Bulgarian => &bulgarian::TABLE,
+            Scheme::SerbianReversible => &serbian_reversible::TABLE,
+        }
+    }
+
+    /// Returns `true` if the language tag (e.g. `sr-Latn-RS` or `sr@latin`) belongs to a language
+    /// written in this scheme
+    pub(crate) fn is_language(self, tag: &str) -> bool {
+        let language = tag.split(['-', '_', '@']).next().unwrap_or_default();
+
+        self.table()
+            .languages
+            .iter()
+            .any(|scheme_language| language.eq_ignore_ascii_case(scheme_language))
+    }
+}
+
+fn title_case(text: &str) -> String {
+    let mut chars = text.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Writes the form in the case of the word (`Sto` and `što` to `Što`)
+pub(crate) fn match_case(word: &str, form: &str) -> String {
+    let letters: Vec<char> = word.chars().filter(|c| c.is_alphabetic()).collect();
+
+    match letters.as_slice() {
+        [_, _, ..] if letters.iter().all(|c| c.is_uppercase()) => form.to_uppercase(),
+        [first, ..] if first.is_uppercase() => title_case(form),
+        _ => form.to_string(),
+    }
+}
+
+/// Compiles lowercase sequences which are converted by the first matching context
+pub(crate) fn compile_rules(
+    rules: &[(&'static str, &'static str, Context)],
+) -> CharTrie<Conversion> {
+    let mut compiled = CharTrie::new();
+
+    for &(sequence, converted, context) in rules {
+        let conversion = compiled.value_mut(sequence.chars()).get_or_insert(Conversion {
+            default: None,
+            rules: vec![],
+        });
+
+        conversion.rules.push((context, converted.to_string()));
+    }
+
+    compiled
+}
+
+impl SchemeTable {
+    pub(crate) fn latin_letters(&self) -> CharTrie<LatinLetter> {
+        let mut letters = CharTrie::new();
+        let sequences = self
+            .letters
+            .iter()
+            .map(|&(cyrillic, latin, kind)| (latin, cyrillic, kind))
+            .chain(self.latin_alternatives.iter().copied());
+
+        // letters which are omitted in latin (e.g. `ь` in Ukrainian) can't be converted back
+        for (latin, cyrillic, kind) in sequences.filter(|(latin, _, _)| !latin.is_empty()) {
+            for (case, variant) in LetterCase::variants(latin) {
+                let letter = letters
+                    .value_mut(variant.chars())
+                    .get_or_insert(LatinLetter {
+                        cyrillic: Conversion {
+                            default: None,
+                            rules: vec![],
+                        },
+                        kind,
+                    });
+
+                if letter.cyrillic.default.is_none() {
+                    letter.cyrillic.default = Some(case.apply(cyrillic));
+                }
+            }
+        }
+
+        let rules = self
+            .latin_rules
+            .iter()
+            .map(|&(latin, cyrillic, context)| (latin, cyrillic, context, LetterKind::Plain))
+            .chain(
+                self.foreign_letters
+                    .iter()
+                    .map(|&(latin, cyrillic, context)| (latin, cyrillic, context, LetterKind::Foreign)),
+            );
+
+        for (latin, cyrillic, context, kind) in rules {
+            for (case, variant) in LetterCase::variants(latin) {
+                let letter = letters
+                    .value_mut(variant.chars())
+                    .get_or_insert(LatinLetter {
+                        cyrillic: Conversion {
+                            default: None,
+                            rules: vec![],
+                        },
+                        kind,
+                    });
+
+                letter.cyrillic.rules.push((context, case.apply(cyrillic)));
+            }
+        }
+
+        letters
+    }
+
+    /// Returns sorted characters which have to be escaped when they are found in the input of
+    /// the conversion to latin, which are the escape itself and all characters of latin sequences
+    pub(crate) fn escaped_chars(&self) -> Vec<char> {
+        let mut chars: Vec<char> = match self.escape {
+            Some(escape) => self
+                .letters
+                .iter()
+                .map(|&(_, latin, _)| latin)
+                .chain(self.latin_alternatives.iter().map(|&(latin, _, _)| latin))
+                .chain(self.latin_rules.iter().map(|&(latin, _, _)| latin))
+                .flat_map(|latin| LetterCase::variants(latin).into_iter())
+                .flat_map(|(_, variant)| variant.chars().collect::<Vec<_>>())
+                .chain([escape])
+                .collect(),
+            None => vec![],
+        };
+
+        chars.sort_unstable();
+        chars.dedup();
+        chars
+    }
+
+    pub(crate) fn cyrillic_letters(&self) -> CharTrie<CyrillicLetter> {
+        let mut letters = CharTrie::new();
+        let sequences = self
+            .letters
+            .iter()
+            .map(|&(cyrillic, latin, _)| (cyrillic, latin, None))
+            .chain(
+                self.cyrillic_rules
+                    .iter()
+                    .map(|&(cyrillic, latin, context)| (cyrillic, latin, Some(context))),
+            );
+
+        for (cyrillic, latin, context) in sequences {
+            for (case, variant) in LetterCase::variants(cyrillic) {
+                let letter = letters
+                    .value_mut(variant.chars())
+                    .get_or_insert(CyrillicLetter {
+                        latin: Conversion {
+                            default: None,
+                            rules: vec![],
+                        },
+                        case,
+                    });
+                let latin = case.apply(latin);
+
+                match context {
+                    Some(context) => letter.latin.rules.push((context, latin)),
+                    None if letter.latin.default.is_none() => letter.latin.default = Some(latin),
+                    None => {}
+                }
+            }
+        }
+
+        letters
+    }
+}
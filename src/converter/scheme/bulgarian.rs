@@ -0,0 +1,49 @@
+use super::Context::WordEnd;
+use super::LetterKind::Plain;
+use super::SchemeTable;
+
+/// Streamlined System, official in Bulgaria since 2009. `ъ` and `а` are both written as `a`, so
+/// `a` is always converted back to `а`.
+pub(crate) static TABLE: SchemeTable = SchemeTable {
+    letters: &[
+        ("а", "a", Plain),
+        ("б", "b", Plain),
+        ("в", "v", Plain),
+        ("г", "g", Plain),
+        ("д", "d", Plain),
+        ("е", "e", Plain),
+        ("ж", "zh", Plain),
+        ("з", "z", Plain),
+        ("и", "i", Plain),
+        ("й", "y", Plain),
+        ("к", "k", Plain),
+        ("л", "l", Plain),
+        ("м", "m", Plain),
+        ("н", "n", Plain),
+        ("о", "o", Plain),
+        ("п", "p", Plain),
+        ("р", "r", Plain),
+        ("с", "s", Plain),
+        ("т", "t", Plain),
+        ("у", "u", Plain),
+        ("ф", "f", Plain),
+        ("х", "h", Plain),
+        ("ц", "ts", Plain),
+        ("ч", "ch", Plain),
+        ("ш", "sh", Plain),
+        ("щ", "sht", Plain),
+        ("ъ", "a", Plain),
+        ("ь", "y", Plain),
+        ("ю", "yu", Plain),
+        ("я", "ya", Plain),
+    ],
+    latin_alternatives: &[],
+    // `-ия` at the end of the word (`София` as `Sofia`)
+    cyrillic_rules: &[("ия", "ia", WordEnd)],
+    latin_rules: &[("ia", "ия", WordEnd)],
+    foreign_letters: &[],
+    impossible_sequences: &[],
+    literal_prefixes: &[],
+    languages: &["bg"],
+    escape: None,
+};
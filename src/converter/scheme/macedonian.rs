@@ -0,0 +1,49 @@
+use super::LetterKind::{Guarded, Plain};
+use super::serbian::{FOREIGN_LETTERS, IMPOSSIBLE_SEQUENCES};
+use super::SchemeTable;
+use crate::constants::MACEDONIAN_LITERAL_PREFIXES;
+
+pub(crate) static TABLE: SchemeTable = SchemeTable {
+    letters: &[
+        ("а", "a", Plain),
+        ("б", "b", Plain),
+        ("в", "v", Plain),
+        ("г", "g", Plain),
+        ("д", "d", Plain),
+        ("ѓ", "ǵ", Plain),
+        ("е", "e", Plain),
+        ("ж", "ž", Plain),
+        ("з", "z", Plain),
+        ("ѕ", "dz", Guarded),
+        ("и", "i", Plain),
+        ("ј", "j", Plain),
+        ("к", "k", Plain),
+        ("л", "l", Plain),
+        ("љ", "lj", Plain),
+        ("м", "m", Plain),
+        ("н", "n", Plain),
+        ("њ", "nj", Guarded),
+        ("о", "o", Plain),
+        ("п", "p", Plain),
+        ("р", "r", Plain),
+        ("с", "s", Plain),
+        ("т", "t", Plain),
+        ("ќ", "ḱ", Plain),
+        ("у", "u", Plain),
+        ("ф", "f", Plain),
+        ("х", "h", Plain),
+        ("ц", "c", Plain),
+        ("ч", "č", Plain),
+        ("џ", "dž", Guarded),
+        ("ш", "š", Plain),
+    ],
+    // `ǵ` and `ḱ` written with the combining acute accent
+    latin_alternatives: &[("g\u{301}", "ѓ", Plain), ("k\u{301}", "ќ", Plain)],
+    cyrillic_rules: &[],
+    latin_rules: &[],
+    foreign_letters: &FOREIGN_LETTERS,
+    impossible_sequences: &IMPOSSIBLE_SEQUENCES,
+    literal_prefixes: &MACEDONIAN_LITERAL_PREFIXES,
+    languages: &["mk"],
+    escape: None,
+};
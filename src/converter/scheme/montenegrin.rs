@@ -0,0 +1,60 @@
+use super::LetterKind::{Dj, Dz, English, Guarded, Plain};
+use super::serbian::{FOREIGN_LETTERS, IMPOSSIBLE_SEQUENCES};
+use super::SchemeTable;
+use crate::constants::LITERAL_PREFIXES;
+
+pub(crate) static TABLE: SchemeTable = SchemeTable {
+    letters: &[
+        ("а", "a", Plain),
+        ("б", "b", Plain),
+        ("в", "v", Plain),
+        ("г", "g", Plain),
+        ("д", "d", Plain),
+        ("ђ", "đ", Plain),
+        ("е", "e", Plain),
+        ("ж", "ž", Plain),
+        ("з", "z", Plain),
+        ("з\u{301}", "ź", Plain),
+        ("и", "i", Plain),
+        ("ј", "j", Plain),
+        ("к", "k", Plain),
+        ("л", "l", Plain),
+        ("љ", "lj", Plain),
+        ("м", "m", Plain),
+        ("н", "n", Plain),
+        ("њ", "nj", Guarded),
+        ("о", "o", Plain),
+        ("п", "p", Plain),
+        ("р", "r", Plain),
+        ("с", "s", Plain),
+        ("с\u{301}", "ś", Plain),
+        ("т", "t", Plain),
+        ("ћ", "ć", Plain),
+        ("у", "u", Plain),
+        ("ф", "f", Plain),
+        ("х", "h", Plain),
+        ("ц", "c", Plain),
+        ("ч", "č", Plain),
+        ("џ", "dž", Guarded),
+        ("ш", "š", Plain),
+    ],
+    latin_alternatives: &[
+        ("dj", "ђ", Dj),
+        ("dz", "џ", Dz),
+        ("sh", "ш", English),
+        ("ch", "ч", English),
+        ("zh", "ж", English),
+        ("cj", "ћ", English),
+        ("tj", "ћ", English),
+        // `ś` and `ź` written with the combining acute accent
+        ("s\u{301}", "с\u{301}", Plain),
+        ("z\u{301}", "з\u{301}", Plain),
+    ],
+    cyrillic_rules: &[],
+    latin_rules: &[],
+    foreign_letters: &FOREIGN_LETTERS,
+    impossible_sequences: &IMPOSSIBLE_SEQUENCES,
+    literal_prefixes: &LITERAL_PREFIXES,
+    languages: &["cnr", "sr", "hr", "bs", "sh"],
+    escape: None,
+};
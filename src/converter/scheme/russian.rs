@@ -0,0 +1,162 @@
+use super::Context::{After, Anywhere, Before, WordStart};
+use super::LetterKind::Plain;
+use super::SchemeTable;
+
+/// ISO 9:1995, where every cyrillic letter maps to exactly one latin letter
+pub(crate) static ISO_9: SchemeTable = SchemeTable {
+    letters: &[
+        ("а", "a", Plain),
+        ("б", "b", Plain),
+        ("в", "v", Plain),
+        ("г", "g", Plain),
+        ("д", "d", Plain),
+        ("е", "e", Plain),
+        ("ё", "ë", Plain),
+        ("ж", "ž", Plain),
+        ("з", "z", Plain),
+        ("и", "i", Plain),
+        ("й", "j", Plain),
+        ("к", "k", Plain),
+        ("л", "l", Plain),
+        ("м", "m", Plain),
+        ("н", "n", Plain),
+        ("о", "o", Plain),
+        ("п", "p", Plain),
+        ("р", "r", Plain),
+        ("с", "s", Plain),
+        ("т", "t", Plain),
+        ("у", "u", Plain),
+        ("ф", "f", Plain),
+        ("х", "h", Plain),
+        ("ц", "c", Plain),
+        ("ч", "č", Plain),
+        ("ш", "š", Plain),
+        ("щ", "ŝ", Plain),
+        ("ъ", "ʺ", Plain),
+        ("ы", "y", Plain),
+        ("ь", "ʹ", Plain),
+        ("э", "è", Plain),
+        ("ю", "û", Plain),
+        ("я", "â", Plain),
+    ],
+    latin_alternatives: &[],
+    cyrillic_rules: &[],
+    latin_rules: &[],
+    foreign_letters: &[],
+    impossible_sequences: &[],
+    literal_prefixes: &[],
+    languages: &["ru"],
+    escape: None,
+};
+
+/// GOST 7.79-2000 system B, which uses only ASCII letters and apostrophes
+pub(crate) static GOST_7_79_B: SchemeTable = SchemeTable {
+    letters: &[
+        ("а", "a", Plain),
+        ("б", "b", Plain),
+        ("в", "v", Plain),
+        ("г", "g", Plain),
+        ("д", "d", Plain),
+        ("е", "e", Plain),
+        ("ё", "yo", Plain),
+        ("ж", "zh", Plain),
+        ("з", "z", Plain),
+        ("и", "i", Plain),
+        ("й", "j", Plain),
+        ("к", "k", Plain),
+        ("л", "l", Plain),
+        ("м", "m", Plain),
+        ("н", "n", Plain),
+        ("о", "o", Plain),
+        ("п", "p", Plain),
+        ("р", "r", Plain),
+        ("с", "s", Plain),
+        ("т", "t", Plain),
+        ("у", "u", Plain),
+        ("ф", "f", Plain),
+        ("х", "x", Plain),
+        ("ц", "cz", Plain),
+        ("ч", "ch", Plain),
+        ("ш", "sh", Plain),
+        ("щ", "shh", Plain),
+        ("ъ", "``", Plain),
+        ("ы", "y`", Plain),
+        ("ь", "`", Plain),
+        ("э", "e`", Plain),
+        ("ю", "yu", Plain),
+        ("я", "ya", Plain),
+    ],
+    latin_alternatives: &[("c", "ц", Plain)],
+    // `ц` is written as `c` before letters written with `e`, `i`, `y` or `j`
+    cyrillic_rules: &[("ц", "c", Before("еёиыйэюя"))],
+    latin_rules: &[],
+    foreign_letters: &[],
+    impossible_sequences: &[],
+    literal_prefixes: &[],
+    languages: &["ru"],
+    escape: None,
+};
+
+/// BGN/PCGN 1947, which writes `е` and `ё` as `ye` and `yë` at the start of the word and after
+/// vowels, and separates `тс` and `шч` with a middle dot
+pub(crate) static BGN_PCGN: SchemeTable = SchemeTable {
+    letters: &[
+        ("а", "a", Plain),
+        ("б", "b", Plain),
+        ("в", "v", Plain),
+        ("г", "g", Plain),
+        ("д", "d", Plain),
+        ("е", "e", Plain),
+        ("ё", "ë", Plain),
+        ("ж", "zh", Plain),
+        ("з", "z", Plain),
+        ("и", "i", Plain),
+        ("й", "y", Plain),
+        ("к", "k", Plain),
+        ("л", "l", Plain),
+        ("м", "m", Plain),
+        ("н", "n", Plain),
+        ("о", "o", Plain),
+        ("п", "p", Plain),
+        ("р", "r", Plain),
+        ("с", "s", Plain),
+        ("т", "t", Plain),
+        ("у", "u", Plain),
+        ("ф", "f", Plain),
+        ("х", "kh", Plain),
+        ("ц", "ts", Plain),
+        ("ч", "ch", Plain),
+        ("ш", "sh", Plain),
+        ("щ", "shch", Plain),
+        ("ъ", "\u{201D}", Plain),
+        ("ы", "y", Plain),
+        ("ь", "\u{2019}", Plain),
+        ("э", "e", Plain),
+        ("ю", "yu", Plain),
+        ("я", "ya", Plain),
+    ],
+    latin_alternatives: &[
+        ("ye", "е", Plain),
+        ("yë", "ё", Plain),
+        ("t·s", "тс", Plain),
+        ("sh·ch", "шч", Plain),
+    ],
+    cyrillic_rules: &[
+        ("е", "ye", WordStart),
+        ("е", "ye", After("аеёиоуыэюяйъь")),
+        ("ё", "yë", WordStart),
+        ("ё", "yë", After("аеёиоуыэюяйъь")),
+        ("тс", "t·s", Anywhere),
+        ("шч", "sh·ch", Anywhere),
+    ],
+    latin_rules: &[
+        ("e", "э", WordStart),
+        ("e", "э", After("aeiouë")),
+        ("y", "ы", After("bcdfghklmnpqrstvwxz")),
+    ],
+    foreign_letters: &[],
+    impossible_sequences: &[],
+    literal_prefixes: &[],
+    languages: &["ru"],
+    escape: None,
+};
@@ -0,0 +1,70 @@
+use super::Context::{self, After, Anywhere, Before};
+use super::LetterKind::{Dj, Dz, English, Guarded, Plain};
+use super::SchemeTable;
+use crate::constants::LITERAL_PREFIXES;
+
+pub(crate) static TABLE: SchemeTable = SchemeTable {
+    letters: &[
+        ("а", "a", Plain),
+        ("б", "b", Plain),
+        ("в", "v", Plain),
+        ("г", "g", Plain),
+        ("д", "d", Plain),
+        ("ђ", "đ", Plain),
+        ("е", "e", Plain),
+        ("ж", "ž", Plain),
+        ("з", "z", Plain),
+        ("и", "i", Plain),
+        ("ј", "j", Plain),
+        ("к", "k", Plain),
+        ("л", "l", Plain),
+        ("љ", "lj", Plain),
+        ("м", "m", Plain),
+        ("н", "n", Plain),
+        ("њ", "nj", Guarded),
+        ("о", "o", Plain),
+        ("п", "p", Plain),
+        ("р", "r", Plain),
+        ("с", "s", Plain),
+        ("т", "t", Plain),
+        ("ћ", "ć", Plain),
+        ("у", "u", Plain),
+        ("ф", "f", Plain),
+        ("х", "h", Plain),
+        ("ц", "c", Plain),
+        ("ч", "č", Plain),
+        ("џ", "dž", Guarded),
+        ("ш", "š", Plain),
+    ],
+    latin_alternatives: &[
+        ("dj", "ђ", Dj),
+        ("dz", "џ", Dz),
+        ("sh", "ш", English),
+        ("ch", "ч", English),
+        ("zh", "ж", English),
+        ("cj", "ћ", English),
+        ("tj", "ћ", English),
+    ],
+    cyrillic_rules: &[],
+    latin_rules: &[],
+    foreign_letters: &FOREIGN_LETTERS,
+    impossible_sequences: &IMPOSSIBLE_SEQUENCES,
+    literal_prefixes: &LITERAL_PREFIXES,
+    languages: &["sr", "hr", "bs", "sh", "cnr"],
+    escape: None,
+};
+
+/// Transcription of `q`, `w`, `x` and `y`, which aren't letters of the alphabet
+pub(super) static FOREIGN_LETTERS: [(&str, &str, Context); 7] = [
+    ("qu", "кв", Anywhere),
+    ("q", "к", Anywhere),
+    ("w", "в", Anywhere),
+    ("x", "кс", Anywhere),
+    ("y", "ј", Before("aeiou")),
+    ("y", "ј", After("aeiou")),
+    ("y", "и", Anywhere),
+];
+
+/// Sequences of letters which don't exist in Serbian words
+pub(super) static IMPOSSIBLE_SEQUENCES: [&str; 10] =
+    ["ck", "cz", "dt", "ee", "gh", "kh", "ph", "th", "tz", "wh"];
@@ -0,0 +1,47 @@
+use super::LetterKind::Plain;
+use super::SchemeTable;
+
+/// Serbian alphabets where `љ`, `њ` and `џ` are written as `l̂`, `n̂` and `d̂`, so every latin
+/// letter is converted back to the cyrillic letter it was converted from
+pub(crate) static TABLE: SchemeTable = SchemeTable {
+    letters: &[
+        ("а", "a", Plain),
+        ("б", "b", Plain),
+        ("в", "v", Plain),
+        ("г", "g", Plain),
+        ("д", "d", Plain),
+        ("ђ", "đ", Plain),
+        ("е", "e", Plain),
+        ("ж", "ž", Plain),
+        ("з", "z", Plain),
+        ("и", "i", Plain),
+        ("ј", "j", Plain),
+        ("к", "k", Plain),
+        ("л", "l", Plain),
+        ("љ", "l\u{302}", Plain),
+        ("м", "m", Plain),
+        ("н", "n", Plain),
+        ("њ", "n\u{302}", Plain),
+        ("о", "o", Plain),
+        ("п", "p", Plain),
+        ("р", "r", Plain),
+        ("с", "s", Plain),
+        ("т", "t", Plain),
+        ("ћ", "ć", Plain),
+        ("у", "u", Plain),
+        ("ф", "f", Plain),
+        ("х", "h", Plain),
+        ("ц", "c", Plain),
+        ("ч", "č", Plain),
+        ("џ", "d\u{302}", Plain),
+        ("ш", "š", Plain),
+    ],
+    latin_alternatives: &[],
+    cyrillic_rules: &[],
+    latin_rules: &[],
+    foreign_letters: &[],
+    impossible_sequences: &[],
+    literal_prefixes: &[],
+    languages: &["sr", "hr", "bs", "sh", "cnr"],
+    escape: Some('\\'),
+};
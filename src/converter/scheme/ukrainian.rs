@@ -0,0 +1,67 @@
+use super::Context::{Anywhere, Before, WordStart};
+use super::LetterKind::Plain;
+use super::SchemeTable;
+
+/// Official Ukrainian transliteration of 2010. Soft sign and apostrophe are omitted, so the
+/// conversion to cyrillic is only approximate.
+pub(crate) static TABLE: SchemeTable = SchemeTable {
+    letters: &[
+        ("а", "a", Plain),
+        ("б", "b", Plain),
+        ("в", "v", Plain),
+        ("г", "h", Plain),
+        ("ґ", "g", Plain),
+        ("д", "d", Plain),
+        ("е", "e", Plain),
+        ("є", "ie", Plain),
+        ("ж", "zh", Plain),
+        ("з", "z", Plain),
+        ("и", "y", Plain),
+        ("і", "i", Plain),
+        ("ї", "i", Plain),
+        ("й", "i", Plain),
+        ("к", "k", Plain),
+        ("л", "l", Plain),
+        ("м", "m", Plain),
+        ("н", "n", Plain),
+        ("о", "o", Plain),
+        ("п", "p", Plain),
+        ("р", "r", Plain),
+        ("с", "s", Plain),
+        ("т", "t", Plain),
+        ("у", "u", Plain),
+        ("ф", "f", Plain),
+        ("х", "kh", Plain),
+        ("ц", "ts", Plain),
+        ("ч", "ch", Plain),
+        ("ш", "sh", Plain),
+        ("щ", "shch", Plain),
+        ("ь", "", Plain),
+        ("ю", "iu", Plain),
+        ("я", "ia", Plain),
+        ("\u{2BC}", "", Plain),
+    ],
+    latin_alternatives: &[("zgh", "зг", Plain)],
+    cyrillic_rules: &[
+        ("є", "ye", WordStart),
+        ("ї", "yi", WordStart),
+        ("й", "y", WordStart),
+        ("ю", "yu", WordStart),
+        ("я", "ya", WordStart),
+        ("зг", "zgh", Anywhere),
+        ("'", "", Before("єїюя")),
+        ("\u{2019}", "", Before("єїюя")),
+    ],
+    latin_rules: &[
+        ("ye", "є", WordStart),
+        ("yi", "ї", WordStart),
+        ("y", "й", WordStart),
+        ("yu", "ю", WordStart),
+        ("ya", "я", WordStart),
+    ],
+    foreign_letters: &[],
+    impossible_sequences: &[],
+    literal_prefixes: &[],
+    languages: &["uk"],
+    escape: None,
+};
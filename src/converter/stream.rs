@@ -0,0 +1,96 @@
+use std::io::{self, ErrorKind, Read, Write};
+use std::str;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::{Converter, Direction};
+
+/// Size of the chunk which is read from the reader at once
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Pending input is converted even without a word boundary once it grows past this size
+const MAX_PENDING_SIZE: usize = 16 * CHUNK_SIZE;
+
+/// Returns length of the longest prefix of `text` which can be converted on its own, i.e. the
+/// prefix ending with the last whitespace. Without whitespace nothing is safe to convert unless
+/// `force` is set, in which case only the last character is held back.
+fn convertible_len(text: &str, force: bool) -> usize {
+    match text.char_indices().rev().find(|(_, c)| c.is_whitespace()) {
+        Some((index, c)) => index + c.len_utf8(),
+        None if force => text.char_indices().last().map_or(0, |(index, _)| index),
+        None => 0,
+    }
+}
+
+/// Returns length of the longest prefix of `text` which consists of whole sentences, i.e. the
+/// prefix ending with the last sentence boundary which is followed by a letter, so more input
+/// can't move it. Used when sentences are converted as a whole, e.g. by language identification.
+fn sentences_len(text: &str) -> usize {
+    let Some((last_letter, _)) = text.char_indices().rev().find(|(_, c)| c.is_alphabetic()) else {
+        return 0;
+    };
+
+    text.split_sentence_bound_indices()
+        .map(|(index, _)| index)
+        .take_while(|&index| index <= last_letter)
+        .last()
+        .unwrap_or(0)
+}
+
+impl Converter {
+    /// Converts everything read from `reader` in the given direction and writes it to `writer`.
+    ///
+    /// Input is processed in chunks, so memory usage doesn't depend on the size of the input.
+    /// Words (and with them digraphs and multi-byte characters) split between two chunks are
+    /// held back until they are complete, as are sentences when language identification is
+    /// enabled, so the output is the same as if the whole input was converted at once (unless a
+    /// single word or sentence is longer than 1 MiB). Returns an error with
+    /// `ErrorKind::InvalidData` if the input is not valid UTF-8.
+    pub fn convert_reader<R: Read, W: Write>(
+        &self,
+        mut reader: R,
+        mut writer: W,
+        direction: Direction,
+    ) -> io::Result<()> {
+        let mut pending: Vec<u8> = Vec::with_capacity(CHUNK_SIZE);
+        let mut chunk = vec![0; CHUNK_SIZE];
+
+        loop {
+            let read = match reader.read(&mut chunk) {
+                Ok(read) => read,
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+
+            if read == 0 {
+                break;
+            }
+
+            pending.extend_from_slice(&chunk[..read]);
+
+            let valid_len = match str::from_utf8(&pending) {
+                Ok(text) => text.len(),
+                Err(error) if error.error_len().is_none() => error.valid_up_to(),
+                Err(error) => return Err(io::Error::new(ErrorKind::InvalidData, error)),
+            };
+
+            // SAFETY: `valid_len` bytes were validated above
+            let text = unsafe { str::from_utf8_unchecked(&pending[..valid_len]) };
+            let force = pending.len() > MAX_PENDING_SIZE;
+            let convertible = match self.config.language_confidence_threshold {
+                Some(_) if direction == Direction::LatinToCyrillic && !force => sentences_len(text),
+                _ => convertible_len(text, force),
+            };
+
+            if convertible > 0 {
+                writer.write_all(self.convert(&text[..convertible], direction).as_bytes())?;
+                pending.drain(..convertible);
+            }
+        }
+
+        let text = str::from_utf8(&pending)
+            .map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?;
+        writer.write_all(self.convert(text, direction).as_bytes())?;
+        writer.flush()
+    }
+}
@@ -0,0 +1,449 @@
+//! Conversion of HTML documents which converts only the text visible to the reader
+
+use std::ops::Range;
+
+use crate::{Converter, Direction, Scheme};
+
+/// Elements whose content is never converted
+const SKIPPED_ELEMENTS: [&str; 7] = ["script", "style", "code", "pre", "kbd", "samp", "var"];
+
+/// Elements whose content is not parsed as HTML
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// Elements which have no content and no end tag
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Attributes which are visible to the reader
+const TEXT_ATTRIBUTES: [&str; 2] = ["title", "alt"];
+
+/// Named character references which are decoded before conversion
+const ENTITIES: [(&str, char); 36] = [
+    ("amp", '&'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("quot", '"'),
+    ("apos", '\''),
+    ("nbsp", '\u{00A0}'),
+    ("shy", '\u{00AD}'),
+    ("scaron", 'š'),
+    ("Scaron", 'Š'),
+    ("zcaron", 'ž'),
+    ("Zcaron", 'Ž'),
+    ("ccaron", 'č'),
+    ("Ccaron", 'Č'),
+    ("cacute", 'ć'),
+    ("Cacute", 'Ć'),
+    ("dstrok", 'đ'),
+    ("Dstrok", 'Đ'),
+    ("ndash", '–'),
+    ("mdash", '—'),
+    ("hellip", '…'),
+    ("laquo", '«'),
+    ("raquo", '»'),
+    ("lsaquo", '‹'),
+    ("rsaquo", '›'),
+    ("bdquo", '„'),
+    ("ldquo", '“'),
+    ("rdquo", '”'),
+    ("sbquo", '‚'),
+    ("lsquo", '‘'),
+    ("rsquo", '’'),
+    ("copy", '©'),
+    ("reg", '®'),
+    ("trade", '™'),
+    ("euro", '€'),
+    ("deg", '°'),
+    ("middot", '·'),
+];
+
+/// Converts HTML documents, touching only the text nodes which are visible to the reader.
+///
+/// Tags, comments and attribute values are copied as they are, as well as content of `script`,
+/// `style`, `code`, `pre`, `kbd`, `samp` and `var` elements, elements marked with
+/// `translate="no"` and elements whose `lang` attribute names a language not written in the
+/// converter's scheme (Serbian, Croatian, Bosnian or Montenegrin by default). Character
+/// references are decoded before conversion, and the converted text is encoded again where needed
+/// (`&`, `<`, `>` and non-breaking space). The `lang` attribute is rewritten to the target script,
+/// e.g. from `sr-Latn` to `sr-Cyrl`.
+///
+/// ```rust
+/// use cyrla::{ConverterBuilder, Direction, HtmlConverter};
+///
+/// let converter = ConverterBuilder::new().build();
+/// let html = HtmlConverter::new(&converter).convert(
+///     r#"<p lang="sr-Latn" class="nav">D&zcaron;ep <code>let x</code></p>"#,
+///     Direction::LatinToCyrillic,
+/// );
+///
+/// assert_eq!(r#"<p lang="sr-Cyrl" class="nav">Џеп <code>let x</code></p>"#, html);
+/// ```
+pub struct HtmlConverter<'c> {
+    converter: &'c Converter,
+    attribute_conversion_enabled: bool,
+}
+
+struct Element {
+    name: String,
+    /// Content is skipped because of the element itself (e.g. `code`) or `translate="no"`
+    skipped: bool,
+    /// Content is skipped because it's written in another language
+    foreign: bool,
+}
+
+struct Attribute {
+    name: String,
+    value: Option<Range<usize>>,
+}
+
+/// Rewrites script subtag of the language tag (e.g. `sr-Latn-RS`) to the target script
+fn rewrite_language(tag: &str, scheme: Scheme, direction: Direction) -> Option<String> {
+    let (from, to) = match direction {
+        Direction::LatinToCyrillic => ("latn", "Cyrl"),
+        Direction::CyrillicToLatin => ("cyrl", "Latn"),
+    };
+
+    if !scheme.is_language(tag) {
+        return None;
+    }
+
+    let mut changed = false;
+    let subtags: Vec<&str> = tag
+        .split('-')
+        .map(|subtag| {
+            if subtag.eq_ignore_ascii_case(from) {
+                changed = true;
+                to
+            } else {
+                subtag
+            }
+        })
+        .collect();
+
+    if changed {
+        Some(subtags.join("-"))
+    } else {
+        None
+    }
+}
+
+/// Decodes character reference at the beginning of `text`, returning the decoded character and
+/// the length of the reference
+pub(crate) fn decode_entity(text: &str) -> Option<(char, usize)> {
+    let end = text.find(';')?;
+    let name = text.get(1..end)?;
+
+    let decoded = if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+
+        char::from_u32(code)?
+    } else {
+        ENTITIES.iter().find(|(entity, _)| *entity == name)?.1
+    };
+
+    Some((decoded, end + 1))
+}
+
+/// Returns length of the character reference at the beginning of `text` which has the form of
+/// a reference (e.g. `&foo;`), but isn't known
+fn unknown_reference_len(text: &str) -> Option<usize> {
+    let end = text.find(';')?;
+
+    if end > 1 && text[1..end].chars().all(|c| c.is_ascii_alphanumeric() || c == '#') {
+        Some(end + 1)
+    } else {
+        None
+    }
+}
+
+fn encode(text: &str, in_attribute: bool, encoded: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => encoded.push_str("&amp;"),
+            '<' => encoded.push_str("&lt;"),
+            '>' => encoded.push_str("&gt;"),
+            '\u{00A0}' => encoded.push_str("&nbsp;"),
+            '"' if in_attribute => encoded.push_str("&quot;"),
+            '\'' if in_attribute => encoded.push_str("&#39;"),
+            _ => encoded.push(c),
+        }
+    }
+}
+
+/// Finds `needle` in `haystack` ignoring ASCII case
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// Parses attributes of the start tag from `tag`, which starts right after the tag name, and
+/// returns them together with the length of the tag including the closing `>`
+fn parse_attributes(tag: &str) -> (Vec<Attribute>, usize) {
+    let bytes = tag.as_bytes();
+    let mut attributes = vec![];
+    let mut i = 0;
+
+    loop {
+        while i < bytes.len() && (bytes[i].is_ascii_whitespace() || bytes[i] == b'/') {
+            i += 1;
+        }
+
+        if i >= bytes.len() {
+            return (attributes, bytes.len());
+        }
+
+        if bytes[i] == b'>' {
+            return (attributes, i + 1);
+        }
+
+        let name_start = i;
+
+        while i < bytes.len() && !bytes[i].is_ascii_whitespace() && !b"=>/".contains(&bytes[i]) {
+            i += 1;
+        }
+
+        let name = tag[name_start..i].to_ascii_lowercase();
+
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        if i >= bytes.len() || bytes[i] != b'=' {
+            attributes.push(Attribute { name, value: None });
+            continue;
+        }
+
+        i += 1;
+
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        let value = match bytes.get(i) {
+            Some(&quote) if quote == b'"' || quote == b'\'' => {
+                let start = i + 1;
+                let end = tag[start..].find(quote as char).map_or(bytes.len(), |end| start + end);
+                i = (end + 1).min(bytes.len());
+                start..end
+            }
+            _ => {
+                let start = i;
+
+                while i < bytes.len() && !bytes[i].is_ascii_whitespace() && bytes[i] != b'>' {
+                    i += 1;
+                }
+
+                start..i
+            }
+        };
+
+        attributes.push(Attribute {
+            name,
+            value: Some(value),
+        });
+    }
+}
+
+impl<'c> HtmlConverter<'c> {
+    pub fn new(converter: &'c Converter) -> HtmlConverter<'c> {
+        HtmlConverter {
+            converter,
+            attribute_conversion_enabled: false,
+        }
+    }
+
+    /// Enables conversion of `title` and `alt` attributes
+    pub fn enable_attribute_conversion(&mut self) -> &mut HtmlConverter<'c> {
+        self.attribute_conversion_enabled = true;
+        self
+    }
+
+    /// Converts the visible text of the HTML document in the given direction
+    pub fn convert(&self, html: &str, direction: Direction) -> String {
+        let mut converted = String::with_capacity(html.len() * 2);
+        let mut stack: Vec<Element> = vec![];
+        let mut i = 0;
+
+        while i < html.len() {
+            let rest = &html[i..];
+
+            let Some(after_bracket) = rest.strip_prefix('<') else {
+                let len = rest.find('<').unwrap_or(rest.len());
+                let is_skipped = stack
+                    .last()
+                    .is_some_and(|element| element.skipped || element.foreign);
+
+                if is_skipped {
+                    converted.push_str(&rest[..len]);
+                } else {
+                    self.convert_text(&rest[..len], direction, false, &mut converted);
+                }
+
+                i += len;
+                continue;
+            };
+
+            i += if after_bracket.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                self.convert_start_tag(rest, direction, &mut stack, &mut converted)
+            } else if after_bracket.starts_with('/') {
+                let len = rest.find('>').map_or(rest.len(), |end| end + 1);
+                let name = rest[2..len]
+                    .split(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+                    .next()
+                    .unwrap_or_default()
+                    .to_ascii_lowercase();
+
+                if let Some(position) = stack.iter().rposition(|element| element.name == name) {
+                    stack.truncate(position);
+                }
+
+                converted.push_str(&rest[..len]);
+                len
+            } else if after_bracket.starts_with(['!', '?']) {
+                let len = if rest.starts_with("<!--") {
+                    rest.find("-->").map_or(rest.len(), |end| end + 3)
+                } else if rest.starts_with("<![CDATA[") {
+                    rest.find("]]>").map_or(rest.len(), |end| end + 3)
+                } else {
+                    rest.find('>').map_or(rest.len(), |end| end + 1)
+                };
+
+                converted.push_str(&rest[..len]);
+                len
+            } else {
+                converted.push_str("&lt;");
+                1
+            };
+        }
+
+        converted
+    }
+
+    /// Converts the start tag at the beginning of `html` together with the content of the element
+    /// if it's raw text element, and returns the length of the converted part
+    fn convert_start_tag(
+        &self,
+        html: &str,
+        direction: Direction,
+        stack: &mut Vec<Element>,
+        converted: &mut String,
+    ) -> usize {
+        let name_end = html[1..]
+            .find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+            .map_or(html.len(), |end| end + 1);
+        let name = html[1..name_end].to_ascii_lowercase();
+        let (attributes, attributes_len) = parse_attributes(&html[name_end..]);
+        let len = name_end + attributes_len;
+        let tag = &html[..len];
+
+        let parent = stack.last();
+        let mut skipped = parent.is_some_and(|element| element.skipped)
+            || SKIPPED_ELEMENTS.contains(&name.as_str());
+        let mut foreign = parent.is_some_and(|element| element.foreign);
+
+        for attribute in &attributes {
+            let value = attribute.value.clone().map_or("", |range| &html[name_end..][range]);
+
+            match attribute.name.as_str() {
+                "translate" if value.eq_ignore_ascii_case("no") => skipped = true,
+                "lang" | "xml:lang" => foreign = !value.is_empty() && !self.converter.scheme().is_language(value),
+                _ => {}
+            }
+        }
+
+        let mut copied = 0;
+
+        for attribute in &attributes {
+            let range = match &attribute.value {
+                Some(range) if !skipped => (range.start + name_end)..(range.end + name_end),
+                _ => continue,
+            };
+            let value = &html[range.clone()];
+
+            let replacement = match attribute.name.as_str() {
+                "lang" | "xml:lang" => rewrite_language(value, self.converter.scheme(), direction),
+                name if !foreign
+                    && self.attribute_conversion_enabled
+                    && TEXT_ATTRIBUTES.contains(&name) =>
+                {
+                    let mut replacement = String::with_capacity(value.len() * 2);
+                    self.convert_text(value, direction, true, &mut replacement);
+                    Some(replacement)
+                }
+                _ => None,
+            };
+
+            if let Some(replacement) = replacement {
+                converted.push_str(&tag[copied..range.start]);
+                converted.push_str(&replacement);
+                copied = range.end;
+            }
+        }
+
+        converted.push_str(&tag[copied..]);
+
+        if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+            let content_len = find_ignore_case(&html[len..], &format!("</{}", name))
+                .unwrap_or(html.len() - len);
+            converted.push_str(&html[len..len + content_len]);
+
+            return len + content_len;
+        }
+
+        if !tag.ends_with("/>") && !VOID_ELEMENTS.contains(&name.as_str()) {
+            stack.push(Element {
+                name,
+                skipped,
+                foreign,
+            });
+        }
+
+        len
+    }
+
+    /// Decodes, converts and encodes the text. Unknown character references are kept as they are.
+    fn convert_text(
+        &self,
+        text: &str,
+        direction: Direction,
+        in_attribute: bool,
+        converted: &mut String,
+    ) {
+        let mut decoded = String::with_capacity(text.len());
+        let mut i = 0;
+
+        while let Some(position) = text[i..].find('&') {
+            decoded.push_str(&text[i..i + position]);
+            i += position;
+
+            if let Some((c, len)) = decode_entity(&text[i..]) {
+                decoded.push(c);
+                i += len;
+                continue;
+            }
+
+            match unknown_reference_len(&text[i..]) {
+                Some(len) => {
+                    encode(&self.converter.convert(&decoded, direction), in_attribute, converted);
+                    converted.push_str(&text[i..i + len]);
+                    decoded.clear();
+                    i += len;
+                }
+                None => {
+                    decoded.push('&');
+                    i += 1;
+                }
+            }
+        }
+
+        decoded.push_str(&text[i..]);
+        encode(&self.converter.convert(&decoded, direction), in_attribute, converted);
+    }
+}
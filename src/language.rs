@@ -0,0 +1,193 @@
+//! Identification of the language of latin text by its letter trigrams
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::script::Script;
+
+/// Bundled sample texts of the languages, the format is described in the file itself
+const SAMPLES: &str = include_str!("../data/language_samples.txt");
+
+/// Language which can be identified by [`identify_language`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    /// Serbian, including Croatian, Bosnian and Montenegrin
+    Serbian,
+    English,
+    German,
+    French,
+    Italian,
+}
+
+impl Language {
+    fn from_tag(tag: &str) -> Option<Language> {
+        match tag {
+            "sr" => Some(Language::Serbian),
+            "en" => Some(Language::English),
+            "de" => Some(Language::German),
+            "fr" => Some(Language::French),
+            "it" => Some(Language::Italian),
+            _ => None,
+        }
+    }
+}
+
+/// Most probable language of the text returned by [`identify_language`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LanguageGuess {
+    pub language: Language,
+    /// Probability of the language among the known ones, between `0.0` and `1.0`
+    pub confidence: f64,
+}
+
+type Trigram = [char; 3];
+
+/// Trigram counts of a single language
+struct Profile {
+    language: Language,
+    counts: HashMap<Trigram, u32>,
+    total: u32,
+}
+
+/// Trigram profiles of the languages, with the number of distinct trigrams among all of them
+struct Model {
+    profiles: Vec<Profile>,
+    distinct: usize,
+}
+
+/// Calls `f` for every trigram of the latin words of the text, which are lowercased, stripped of
+/// diacritics of Gaj's alphabet and padded with a space on both sides
+fn for_each_trigram<F>(text: &str, mut f: F)
+where
+    F: FnMut(Trigram),
+{
+    let mut window = [' '; 3];
+    let mut len = 1;
+    let mut push = |c: char| {
+        if c == ' ' && window[2] == ' ' {
+            return;
+        }
+
+        window = [window[1], window[2], c];
+        len += 1;
+
+        if len >= 3 {
+            f(window);
+        }
+
+        if c == ' ' {
+            len = 1;
+        }
+    };
+
+    for c in text.chars().flat_map(char::to_lowercase) {
+        match c {
+            'č' | 'ć' => push('c'),
+            'š' => push('s'),
+            'ž' => push('z'),
+            'đ' => {
+                push('d');
+                push('j');
+            }
+            _ if Script::of(c) == Some(Script::Latin) => push(c),
+            _ => push(' '),
+        }
+    }
+
+    push(' ');
+}
+
+fn model() -> &'static Model {
+    static MODEL: OnceLock<Model> = OnceLock::new();
+
+    MODEL.get_or_init(|| {
+        let mut profiles: Vec<Profile> = vec![];
+
+        for line in SAMPLES.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(tag) = line
+                .strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'))
+            {
+                if let Some(language) = Language::from_tag(tag) {
+                    profiles.push(Profile {
+                        language,
+                        counts: HashMap::new(),
+                        total: 0,
+                    });
+                }
+                continue;
+            }
+
+            if let Some(profile) = profiles.last_mut() {
+                for_each_trigram(line, |trigram| {
+                    *profile.counts.entry(trigram).or_insert(0) += 1;
+                    profile.total += 1;
+                });
+            }
+        }
+
+        let mut distinct: Vec<&Trigram> = profiles
+            .iter()
+            .flat_map(|profile| profile.counts.keys())
+            .collect();
+        distinct.sort_unstable();
+        distinct.dedup();
+        let distinct = distinct.len();
+
+        Model { profiles, distinct }
+    })
+}
+
+/// Identifies the language of latin text by comparing its letter trigrams with the bundled
+/// profiles of Serbian, English, German, French and Italian. Returns `None` if the text has no
+/// latin letters. Short texts have few trigrams, so their guesses have lower confidence.
+///
+/// ```rust
+/// use cyrla::language::{identify_language, Language};
+///
+/// let guess = identify_language("The weather will be nice tomorrow.").unwrap();
+/// assert_eq!(guess.language, Language::English);
+/// ```
+pub fn identify_language(text: &str) -> Option<LanguageGuess> {
+    let model = model();
+    let mut trigrams = vec![];
+
+    for_each_trigram(text, |trigram| trigrams.push(trigram));
+
+    if trigrams.is_empty() {
+        return None;
+    }
+
+    // naive Bayes with add-one smoothing, where every language is equally probable
+    let scores: Vec<f64> = model
+        .profiles
+        .iter()
+        .map(|profile| {
+            let total = (profile.total as usize + model.distinct) as f64;
+
+            trigrams
+                .iter()
+                .map(|trigram| {
+                    let count = profile.counts.get(trigram).copied().unwrap_or(0);
+                    ((count + 1) as f64 / total).ln()
+                })
+                .sum()
+        })
+        .collect();
+
+    let (best, best_score) = scores
+        .iter()
+        .copied()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))?;
+    let sum: f64 = scores.iter().map(|score| (score - best_score).exp()).sum();
+
+    Some(LanguageGuess {
+        language: model.profiles[best].language,
+        confidence: 1.0 / sum,
+    })
+}
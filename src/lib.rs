@@ -14,8 +14,7 @@
 //! ```rust
 //! use cyrla::ConverterBuilder;
 //!
-//! let mut builder = ConverterBuilder::new();
-//! let converter = builder.build();
+//! let converter = ConverterBuilder::new().build();
 //!
 //! // conversion from latin to cyrillic script
 //! let cyrillic_text = converter.lat_to_cyr("Konjugacija u genetici je sparivanje homolognih hromozoma u mejozi.");
@@ -31,11 +30,9 @@
 //! ```rust
 //! use cyrla::ConverterBuilder;
 //!
-//! let mut builder = ConverterBuilder::new();
-//! let mut prefixes = vec!["kunjar", "abanjar", "sanjar"];
-//! let converter = builder
+//! let converter = ConverterBuilder::new()
 //!     .enable_ijekavian_inclusion()
-//!     .extend_dictionary(&mut prefixes)
+//!     .extend_dictionary(["kunjar", "abanjar", "sanjar"])
 //!     .build();
 //!
 //! let cyrillic_text = converter.lat_to_cyr("Kunjar, Abanjar i Sanjar sudjelovali su u zadatku.");
@@ -43,19 +40,74 @@
 //! ```
 //!
 //! You can find all available options in the `ConverterBuilder` documentation.
+//!
+//! Converter owns all of its data, so it can be stored in a `static` and shared between threads:
+//!
+//! ```rust
+//! use std::sync::OnceLock;
+//! use cyrla::{Converter, ConverterBuilder};
+//!
+//! static CONVERTER: OnceLock<Converter> = OnceLock::new();
+//!
+//! let converter = CONVERTER.get_or_init(|| ConverterBuilder::new().enable_bald_latin().build());
+//! let cyrillic_text = std::thread::spawn(move || converter.lat_to_cyr("Djurdjevdan"))
+//!     .join()
+//!     .unwrap();
+//!
+//! assert_eq!(cyrillic_text, "Ђурђевдан");
+//! ```
 
 pub mod constants;
 pub mod converter;
+pub mod html;
+pub mod language;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+pub mod po;
+pub mod report;
+pub mod script;
+mod tokenizer;
 
-pub use converter::{Converter, ConverterBuilder};
+pub use converter::{
+    Converter, ConverterBuilder, Dialect, DigraphCase, Direction, ForeignLetterPolicy,
+    ForeignWordRule, RestorationFallback, Scheme, SourceLanguage,
+};
+pub use html::HtmlConverter;
+pub use language::{identify_language, Language, LanguageGuess};
+#[cfg(feature = "markdown")]
+pub use markdown::MarkdownConverter;
+pub use report::{ConversionReport, ForeignLettersError, Issue, IssueKind};
+pub use script::{detect_script, Script, ScriptReport};
 
 #[cfg(test)]
 mod tests {
-    use crate::ConverterBuilder;
+    use crate::{
+        detect_script, identify_language, ConverterBuilder, Dialect, DigraphCase, Direction,
+        ForeignLetterPolicy, ForeignWordRule, HtmlConverter, IssueKind, Language,
+        RestorationFallback, Scheme, Script, SourceLanguage,
+    };
+    use proptest::prelude::*;
+    use std::io::{self, Read};
+
+    /// Reader which returns at most one byte per read, splitting every character and digraph
+    struct ByteByByteReader<'a>(&'a [u8]);
+
+    impl<'a> Read for ByteByByteReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match (self.0.split_first(), buf.first_mut()) {
+                (Some((byte, rest)), Some(first)) => {
+                    *first = *byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
 
     #[test]
     fn it_properly_converts_latin_to_cyrillic() {
-        let mut builder = ConverterBuilder::new();
+        let builder = ConverterBuilder::new();
         let cyrillic_text =
             builder.build().lat_to_cyr("'Oće centrala da pogreši jednom, ali ne sto puta!");
 
@@ -67,7 +119,7 @@ mod tests {
 
     #[test]
     fn it_properly_converts_cyrillic_to_latin() {
-        let mut builder = ConverterBuilder::new();
+        let builder = ConverterBuilder::new();
         let latin_text = builder.build().cyr_to_lat("Ђурђевак је њена омиљена биљка.");
 
         assert_eq!("Đurđevak je njena omiljena biljka.", latin_text);
@@ -75,7 +127,7 @@ mod tests {
 
     #[test]
     fn it_properly_converts_literal_prefixes() {
-        let mut builder = ConverterBuilder::new();
+        let builder = ConverterBuilder::new();
         let cyrillic_text = builder
             .build()
             .lat_to_cyr("Konjugacija u genetici je sparivanje homolognih hromozoma u mejozi.");
@@ -89,9 +141,9 @@ mod tests {
     #[test]
     fn it_properly_converts_words_added_to_dictionary() {
         let mut builder = ConverterBuilder::new();
-        let mut words = vec!["kunjar", "abanjar", "sanjar"];
+        let words = vec!["kunjar", "abanjar", "sanjar"];
         let converter = builder
-            .extend_dictionary(&mut words)
+            .extend_dictionary(words)
             .enable_ijekavian_inclusion()
             .build();
         let cyrillic_text =
@@ -129,13 +181,955 @@ mod tests {
     #[test]
     fn it_does_not_convert_ignored_latin_words() {
         let mut builder = ConverterBuilder::new();
-        let mut ignored_words = vec!["C#", "C++", "R", "Python"];
+        let ignored_words = vec!["C#", "C++", "R", "Python"];
         let converter = builder
-            .add_ignored_latin_words(&mut ignored_words)
+            .add_ignored_latin_words(ignored_words)
             .build();
 
         let cyrillic_text = converter.lat_to_cyr("Jezici koji se koriste na projektu su C# , C++ , R i Python");
 
         assert_eq!("Језици који се користе на пројекту су C# , C++ , R и Python", cyrillic_text);
     }
+
+    #[test]
+    fn it_converts_streams_split_inside_words_and_characters() {
+        let mut builder = ConverterBuilder::new();
+        let ignored_words = vec!["Python"];
+        let converter = builder.add_ignored_latin_words(ignored_words).build();
+        let input = "Konjugacija u genetici, Python i džezva njenog Đurđevdana";
+
+        let mut output = vec![];
+        converter
+            .convert_reader(ByteByByteReader(input.as_bytes()), &mut output, Direction::LatinToCyrillic)
+            .unwrap();
+
+        assert_eq!(converter.lat_to_cyr(input), String::from_utf8(output).unwrap());
+
+        let mut output = vec![];
+        converter
+            .convert_reader(ByteByByteReader("Ђурђевак је њена биљка".as_bytes()), &mut output, Direction::CyrillicToLatin)
+            .unwrap();
+
+        assert_eq!("Đurđevak je njena biljka", String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn it_converts_streams_split_inside_foreign_sentences() {
+        let mut builder = ConverterBuilder::new();
+        let converter = builder.enable_language_identification().build();
+        let input = "Uputstvo je na engleskom. Please read the manual before you start. Hvala!\n";
+
+        let mut output = vec![];
+        converter
+            .convert_reader(ByteByByteReader(input.as_bytes()), &mut output, Direction::LatinToCyrillic)
+            .unwrap();
+
+        assert_eq!(
+            "Упутство је на енглеском. Please read the manual before you start. Хвала!\n",
+            String::from_utf8(output).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_fails_to_convert_streams_with_invalid_utf8() {
+        let builder = ConverterBuilder::new();
+        let converter = builder.build();
+        let mut output = vec![];
+
+        let result = converter.convert_reader(&b"abc \xff def"[..], &mut output, Direction::LatinToCyrillic);
+
+        assert_eq!(io::ErrorKind::InvalidData, result.unwrap_err().kind());
+    }
+
+    #[test]
+    fn it_detects_script_of_text_and_words() {
+        let report = detect_script("Ово је mixed текст, а Wи-Фи није");
+
+        assert_eq!(6, report.latin_letters);
+        assert_eq!(18, report.cyrillic_letters);
+        assert_eq!(Some(Script::Cyrillic), report.dominant_script());
+        assert_eq!(None, report.script());
+        assert_eq!(Some(Script::Latin), report.words[2].script());
+        assert!(report.words[5].is_mixed());
+        assert_eq!(1.0 / 7.0, report.mixed_ratio());
+    }
+
+    #[test]
+    fn it_converts_only_words_not_in_target_script() {
+        let builder = ConverterBuilder::new();
+        let converter = builder.build();
+        let input = "Ђурђевак је njena omiljena биљка.";
+
+        let cyrillic_text = converter.to_script(input, Script::Cyrillic);
+        assert_eq!("Ђурђевак је њена омиљена биљка.", cyrillic_text);
+        assert_eq!(cyrillic_text, converter.to_script(&cyrillic_text, Script::Cyrillic));
+
+        let latin_text = converter.to_script(input, Script::Latin);
+        assert_eq!("Đurđevak je njena omiljena biljka.", latin_text);
+        assert_eq!(latin_text, converter.to_script(&latin_text, Script::Latin));
+    }
+
+    #[test]
+    fn it_matches_ignored_latin_words_next_to_punctuation() {
+        let mut builder = ConverterBuilder::new();
+        let ignored_words = vec!["C#", "C++", ".NET", "Wi-Fi", "Python"];
+        let converter = builder.add_ignored_latin_words(ignored_words).build();
+
+        let cyrillic_text = converter.lat_to_cyr("Koriste se C#, C++\t(.NET) i \"Python\".\nWi-Fi je crno-beli");
+
+        assert_eq!("Користе се C#, C++\t(.NET) и \"Python\".\nWi-Fi је црно-бели", cyrillic_text);
+    }
+
+    #[test]
+    fn it_looks_up_dictionary_for_words_after_punctuation_and_in_compounds() {
+        let builder = ConverterBuilder::new();
+        let converter = builder.build();
+
+        let cyrillic_text = converter.lat_to_cyr("(konjugacija) i anti-injekcija");
+
+        assert_eq!("(конјугација) и анти-инјекција", cyrillic_text);
+    }
+
+    #[test]
+    fn it_converts_digraphs_to_uppercase_in_uppercase_words() {
+        let builder = ConverterBuilder::new();
+        let latin_text = builder.build().cyr_to_lat("ЉУБАВ И КОЊ, Љубав, ЊИС и Џ");
+
+        assert_eq!("LJUBAV I KONJ, Ljubav, NJIS i Dž", latin_text);
+    }
+
+    #[test]
+    fn it_converts_single_letter_digraphs_to_configured_case() {
+        let mut builder = ConverterBuilder::new();
+        let converter = builder.set_single_letter_digraph_case(DigraphCase::Upper).build();
+
+        assert_eq!("DŽ. Džep", converter.cyr_to_lat("Џ. Џеп"));
+    }
+
+    #[test]
+    fn it_converts_only_visible_text_of_html() {
+        let builder = ConverterBuilder::new();
+        let converter = builder.build();
+        let html = r#"<!DOCTYPE html>
+<html lang="sr-Latn-RS">
+<head><title>Naslov</title><style>.nj { color: red }</style></head>
+<body class="njiva">
+<!-- komentar -->
+<p title="Šta je ovo?" data-x='nj'>Njiva &amp; &scaron;uma&nbsp;&mdash; &foo; <a href="/njiva">link</a></p>
+<p lang="en">English text <span lang="sr">i naš</span></p>
+<div translate="no">Ne <b>menjaj</b></div><pre>kod</pre><br>
+<script>var njiva = "<p>";</script>
+</body>
+</html>"#;
+
+        let converted = HtmlConverter::new(&converter)
+            .enable_attribute_conversion()
+            .convert(html, Direction::LatinToCyrillic);
+
+        assert_eq!(
+            r#"<!DOCTYPE html>
+<html lang="sr-Cyrl-RS">
+<head><title>Наслов</title><style>.nj { color: red }</style></head>
+<body class="njiva">
+<!-- komentar -->
+<p title="Шта је ово?" data-x='nj'>Њива &amp; шума&nbsp;— &foo; <a href="/njiva">линк</a></p>
+<p lang="en">English text <span lang="sr">и наш</span></p>
+<div translate="no">Ne <b>menjaj</b></div><pre>kod</pre><br>
+<script>var njiva = "<p>";</script>
+</body>
+</html>"#,
+            converted
+        );
+    }
+
+    #[test]
+    fn it_converts_html_text_starting_with_multibyte_characters() {
+        let converter = ConverterBuilder::new().build();
+        let html_converter = HtmlConverter::new(&converter);
+
+        assert_eq!(
+            "<p>Đak</p>",
+            html_converter.convert("<p>Ђак</p>", Direction::CyrillicToLatin)
+        );
+        assert_eq!(
+            "Šuma <b>Šargan</b>",
+            html_converter.convert("Шума <b>Шарган</b>", Direction::CyrillicToLatin)
+        );
+    }
+
+    #[cfg(feature = "markdown")]
+    #[test]
+    fn it_converts_only_prose_of_markdown() {
+        let builder = ConverterBuilder::new();
+        let converter = builder.build();
+        let markdown = "---
+title: Njiva
+tags: [nj]
+---
+
+# Naslov {#naslov}
+
+Pogledaj **njivu** na [sajtu](https://njiva.rs \"Njiva\"), <https://njiva.rs> i [ref][njiva].
+![Slika njive](slika.png) &scaron;uma, `let njiva = 1;`
+
+```rust
+let njiva = \"njiva\";
+```
+
+    uvučen kod
+
+- [x] zadatak <span class=\"nj\">tekst</span>
+
+[njiva]: https://njiva.rs
+";
+
+        let converted = crate::MarkdownConverter::new(&converter).convert(markdown, Direction::LatinToCyrillic);
+
+        assert_eq!(
+            "---
+title: Njiva
+tags: [nj]
+---
+
+# Наслов {#naslov}
+
+Погледај **њиву** на [сајту](https://njiva.rs \"Njiva\"), <https://njiva.rs> и [реф][njiva].
+![Слика њиве](slika.png) шума, `let njiva = 1;`
+
+```rust
+let njiva = \"njiva\";
+```
+
+    uvučen kod
+
+- [x] задатак <span class=\"nj\">текст</span>
+
+[njiva]: https://njiva.rs
+",
+            converted
+        );
+    }
+
+    #[cfg(feature = "markdown")]
+    #[test]
+    fn it_keeps_labels_of_shortcut_and_collapsed_markdown_links() {
+        let converter = ConverterBuilder::new().build();
+        let markdown = "Vidi [njiva] i [sajt][], ![slika] i [njivu][njiva].
+
+[njiva]: https://njiva.rs
+[sajt]: https://sajt.rs
+[slika]: slika.png
+";
+
+        assert_eq!(
+            "Види [njiva] и [sajt][], ![slika] и [њиву][njiva].
+
+[njiva]: https://njiva.rs
+[sajt]: https://sajt.rs
+[slika]: slika.png
+",
+            crate::MarkdownConverter::new(&converter).convert(markdown, Direction::LatinToCyrillic)
+        );
+    }
+
+    #[cfg(feature = "markdown")]
+    #[test]
+    fn it_decodes_character_references_of_letters_in_markdown() {
+        let converter = ConverterBuilder::new().build();
+        let markdown = "&Scaron;uma i &#269;i&#x161;ta &amp; &#42;njiva&#42; &nepoznato;\n";
+
+        assert_eq!(
+            "Шума и чишта &amp; &#42;њива&#42; &nepoznato;\n",
+            crate::MarkdownConverter::new(&converter).convert(markdown, Direction::LatinToCyrillic)
+        );
+    }
+
+    #[test]
+    fn it_transliterates_po_files() {
+        let builder = ConverterBuilder::new();
+        let converter = builder.build();
+        let po = r#"# Serbian translation
+msgid ""
+msgstr ""
+"Project-Id-Version: app 1.0\n"
+"Language: sr_RS\n"
+"Plural-Forms: nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\n"
+
+#: src/main.c:12
+#, fuzzy, c-format
+msgid "%d file of %s"
+msgid_plural "%d files of %s"
+msgstr[0] "%d фајл од %s"
+msgstr[1] "%d фајла од %s"
+msgstr[2] "%d фајлова од %s"
+
+msgctxt "menu"
+msgid "Open {name}"
+msgstr ""
+"Отвори {name}\n"
+"%(count)d пута"
+
+#~ msgid "Old"
+#~ msgstr "Старо"
+"#;
+
+        let mut file = crate::po::PoFile::parse(po).unwrap();
+        assert!(file.entries[1].is_fuzzy());
+        assert_eq!(vec!["fuzzy", "c-format"], file.entries[1].flags());
+
+        file.transliterate(&converter, Direction::CyrillicToLatin);
+
+        assert_eq!(
+            r#"# Serbian translation
+msgid ""
+msgstr ""
+"Project-Id-Version: app 1.0\n"
+"Language: sr_RS@latin\n"
+"Plural-Forms: nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\n"
+
+#: src/main.c:12
+#, fuzzy, c-format
+msgid "%d file of %s"
+msgid_plural "%d files of %s"
+msgstr[0] "%d fajl od %s"
+msgstr[1] "%d fajla od %s"
+msgstr[2] "%d fajlova od %s"
+
+msgctxt "menu"
+msgid "Open {name}"
+msgstr ""
+"Otvori {name}\n"
+"%(count)d puta"
+
+#~ msgid "Old"
+#~ msgstr "Старо"
+"#,
+            file.to_string()
+        );
+    }
+
+    #[test]
+    fn it_converts_braces_in_po_translations_which_are_not_placeholders() {
+        let converter = ConverterBuilder::new().build();
+        let po = "msgid \"Fields\"\r\nmsgstr \"{Njiva, polje} {0} {name:>10} {:,.2f}\"\r\n";
+
+        let mut file = crate::po::PoFile::parse(po).unwrap();
+        file.transliterate(&converter, Direction::LatinToCyrillic);
+
+        assert_eq!(
+            "msgid \"Fields\"\r\nmsgstr \"{Њива, поље} {0} {name:>10} {:,.2f}\"\r\n",
+            file.to_string()
+        );
+    }
+
+    #[test]
+    fn it_builds_several_independent_converters_from_the_same_builder() {
+        fn assert_static_send_sync_clone<T: 'static + Send + Sync + Clone>(_: &T) {}
+
+        let mut builder = ConverterBuilder::new();
+        builder.extend_dictionary(vec![String::from("kunjar")]);
+        let first = builder.build();
+        let second = builder.clone().enable_bald_latin().build();
+        let third = builder.build();
+
+        assert_static_send_sync_clone(&first);
+        assert_eq!("Кунјар и Дјурдјевдан", first.lat_to_cyr("Kunjar i Djurdjevdan"));
+        assert_eq!("Кунјар и Ђурђевдан", second.lat_to_cyr("Kunjar i Djurdjevdan"));
+        assert_eq!("Кунјар и Дјурдјевдан", third.clone().lat_to_cyr("Kunjar i Djurdjevdan"));
+    }
+
+    #[test]
+    fn it_converts_macedonian_in_both_directions() {
+        let converter = ConverterBuilder::new().use_scheme(Scheme::Macedonian).build();
+        let cyrillic = "Ѓорѓи ќе ја види ѕвездата над ЅИДОТ, а Џем во подземјето.";
+        let latin = "Ǵorǵi ḱe ja vidi dzvezdata nad DZIDOT, a Džem vo podzemjeto.";
+
+        assert_eq!(latin, converter.cyr_to_lat(cyrillic));
+        assert_eq!(cyrillic, converter.lat_to_cyr(latin));
+        assert_eq!("Ѓорѓи ќе", converter.lat_to_cyr("G\u{301}org\u{301}i k\u{301}e"));
+    }
+
+    #[test]
+    fn it_converts_only_languages_of_the_scheme_in_html() {
+        let converter = ConverterBuilder::new().use_scheme(Scheme::Macedonian).build();
+        let html = r#"<p lang="mk-Latn">Dzvezda <span lang="sr">Zvezda</span></p>"#;
+
+        assert_eq!(
+            r#"<p lang="mk-Cyrl">Ѕвезда <span lang="sr">Zvezda</span></p>"#,
+            HtmlConverter::new(&converter).convert(html, Direction::LatinToCyrillic)
+        );
+    }
+
+    #[test]
+    fn it_converts_montenegrin_letters_in_both_encodings() {
+        let converter = ConverterBuilder::new().use_scheme(Scheme::Montenegrin).build();
+        let cyrillic = "С\u{301}утра ће с\u{301}ести на из\u{301}ести, ЗНАМ С\u{301}ЕКИРУ.";
+        let latin = "Śutra će śesti na iźesti, ZNAM ŚEKIRU.";
+        let decomposed_latin = "S\u{301}utra će s\u{301}esti na iz\u{301}esti, ZNAM S\u{301}EKIRU.";
+
+        assert_eq!(latin, converter.cyr_to_lat(cyrillic));
+        assert_eq!(cyrillic, converter.lat_to_cyr(latin));
+        assert_eq!(cyrillic, converter.lat_to_cyr(decomposed_latin));
+    }
+
+    #[test]
+    fn it_transliterates_russian_in_both_directions() {
+        let cyrillic = "Щука и ёжик, Юрьевна, ЩУКА, Цыгане, объявление, Братск, Достоевский";
+        let schemes = [
+            (Scheme::RussianIso9, "Ŝuka i ëžik, Ûrʹevna, ŜUKA, Cygane, obʺâvlenie, Bratsk, Dostoevskij"),
+            (Scheme::RussianGost779B, "Shhuka i yozhik, Yur`evna, SHHUKA, Cy`gane, ob``yavlenie, Bratsk, Dostoevskij"),
+            (Scheme::RussianBgnPcgn, "Shchuka i yëzhik, Yur’yevna, SHCHUKA, Tsygane, ob”yavleniye, Brat·sk, Dostoyevskiy"),
+        ];
+
+        for (scheme, latin) in schemes {
+            let converter = ConverterBuilder::new().use_scheme(scheme).build();
+
+            assert_eq!(latin, converter.cyr_to_lat(cyrillic));
+            assert_eq!(cyrillic, converter.lat_to_cyr(latin));
+        }
+    }
+
+    /// Examples from the resolution of the Cabinet of Ministers of Ukraine No. 55 of 2010
+    const UKRAINIAN_PASSPORT_NAMES: [(&str, &str); 74] = [
+        ("Алушта", "Alushta"),
+        ("Андрій", "Andrii"),
+        ("Борщагівка", "Borshchahivka"),
+        ("Борисенко", "Borysenko"),
+        ("Вінниця", "Vinnytsia"),
+        ("Володимир", "Volodymyr"),
+        ("Гадяч", "Hadiach"),
+        ("Богдан", "Bohdan"),
+        ("Згурський", "Zghurskyi"),
+        ("Ґалаґан", "Galagan"),
+        ("Ґорґани", "Gorgany"),
+        ("Донецьк", "Donetsk"),
+        ("Дмитро", "Dmytro"),
+        ("Рівне", "Rivne"),
+        ("Олег", "Oleh"),
+        ("Есмань", "Esman"),
+        ("Єнакієве", "Yenakiieve"),
+        ("Гаєвич", "Haievych"),
+        ("Короп'є", "Koropie"),
+        ("Житомир", "Zhytomyr"),
+        ("Жанна", "Zhanna"),
+        ("Жежелів", "Zhezheliv"),
+        ("Закарпаття", "Zakarpattia"),
+        ("Казимирчук", "Kazymyrchuk"),
+        ("Медвин", "Medvyn"),
+        ("Михайленко", "Mykhailenko"),
+        ("Іванків", "Ivankiv"),
+        ("Іващенко", "Ivashchenko"),
+        ("Їжакевич", "Yizhakevych"),
+        ("Кадиївка", "Kadyivka"),
+        ("Мар'їне", "Marine"),
+        ("Йосипівка", "Yosypivka"),
+        ("Стрий", "Stryi"),
+        ("Олексій", "Oleksii"),
+        ("Київ", "Kyiv"),
+        ("Коваленко", "Kovalenko"),
+        ("Лебедин", "Lebedyn"),
+        ("Леонід", "Leonid"),
+        ("Миколаїв", "Mykolaiv"),
+        ("Маринич", "Marynych"),
+        ("Ніжин", "Nizhyn"),
+        ("Наталія", "Nataliia"),
+        ("Одеса", "Odesa"),
+        ("Онищенко", "Onyshchenko"),
+        ("Полтава", "Poltava"),
+        ("Петро", "Petro"),
+        ("Решетилівка", "Reshetylivka"),
+        ("Рибчинський", "Rybchynskyi"),
+        ("Суми", "Sumy"),
+        ("Соломія", "Solomiia"),
+        ("Тернопіль", "Ternopil"),
+        ("Троць", "Trots"),
+        ("Ужгород", "Uzhhorod"),
+        ("Уляна", "Uliana"),
+        ("Фастів", "Fastiv"),
+        ("Філіпчук", "Filipchuk"),
+        ("Харків", "Kharkiv"),
+        ("Христина", "Khrystyna"),
+        ("Біла Церква", "Bila Tserkva"),
+        ("Стеценко", "Stetsenko"),
+        ("Чернівці", "Chernivtsi"),
+        ("Шевченко", "Shevchenko"),
+        ("Шостка", "Shostka"),
+        ("Кишеньки", "Kyshenky"),
+        ("Щербухи", "Shcherbukhy"),
+        ("Гоща", "Hoshcha"),
+        ("Гаращенко", "Harashchenko"),
+        ("Юрій", "Yurii"),
+        ("Корюківка", "Koriukivka"),
+        ("Яготин", "Yahotyn"),
+        ("Ярошенко", "Yaroshenko"),
+        ("Костянтин", "Kostiantyn"),
+        ("Знам'янка", "Znamianka"),
+        ("Феодосія", "Feodosiia"),
+    ];
+
+    #[test]
+    fn it_transliterates_ukrainian_passport_names() {
+        let converter = ConverterBuilder::new().use_scheme(Scheme::Ukrainian).build();
+
+        for (cyrillic, latin) in UKRAINIAN_PASSPORT_NAMES {
+            assert_eq!(latin, converter.cyr_to_lat(cyrillic), "{}", cyrillic);
+        }
+
+        assert_eq!("ZGHURSKYI YURII", converter.cyr_to_lat("ЗГУРСЬКИЙ ЮРІЙ"));
+        assert_eq!("Znamianka", converter.cyr_to_lat("Знам\u{2019}янка"));
+    }
+
+    #[test]
+    fn it_transliterates_bulgarian_with_word_final_rules() {
+        let converter = ConverterBuilder::new().use_scheme(Scheme::Bulgarian).build();
+
+        assert_eq!(
+            "Sofia, Balgaria, Shtastlivets, Tsvetan i Yordan sa v Plovdiv, Diana, Lyuben, Gyorgi, AVSTRIA",
+            converter.cyr_to_lat("София, България, Щастливец, Цветан и Йордан са в Пловдив, Диана, Любен, Гьорги, АВСТРИЯ")
+        );
+        assert_eq!(
+            "София, Щастливец, Цветан и Йордан са в Пловдив, Диана, Любен",
+            converter.lat_to_cyr("Sofia, Shtastlivets, Tsvetan i Yordan sa v Plovdiv, Diana, Lyuben")
+        );
+    }
+
+    #[test]
+    fn it_converts_reversibly_with_split_digraphs_and_latin_input() {
+        let converter = ConverterBuilder::new().use_scheme(Scheme::SerbianReversible).build();
+        let cyrillic = "Коњугација и конјугација, ЉУБАВ, џез, поддржати, C:\\ и Python";
+        let latin = converter.cyr_to_lat(cyrillic);
+
+        assert_eq!(
+            "Kon\u{302}ugacija i konjugacija, L\u{302}UBAV, d\u{302}ez, poddržati, \\C:\\\\ i \\Py\\t\\h\\o\\n",
+            latin
+        );
+        assert_eq!(cyrillic, converter.lat_to_cyr(&latin));
+    }
+
+    #[test]
+    fn it_applies_word_options_to_reversible_latin() {
+        let mut builder = ConverterBuilder::new();
+        builder
+            .use_scheme(Scheme::SerbianReversible)
+            .add_ignored_latin_words(["Python"])
+            .enable_foreign_word_detection();
+        let input = "Python i iPhone na n\u{302}ivi, \\Cas";
+        let report = builder.build().lat_to_cyr_with_report(input);
+
+        assert_eq!("Python и iPhone на њиви, Cас", report.text);
+        assert_eq!(
+            vec!["iPhone"],
+            report
+                .issues
+                .iter()
+                .map(|issue| &input[issue.range.clone()])
+                .collect::<Vec<_>>()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn it_converts_reversibly_any_text(text in "\\PC*") {
+            let converter = ConverterBuilder::new().use_scheme(Scheme::SerbianReversible).build();
+
+            prop_assert_eq!(&text, &converter.lat_to_cyr(&converter.cyr_to_lat(&text)));
+        }
+
+        #[test]
+        fn it_converts_reversibly_mixed_scripts(
+            text in "[абвгдђежзијклљмнњопрстћуфхцчџшАБВГДЂЕЖЗИЈКЛЉМНЊОПРСТЋУФХЦЧЏШa-zA-ZčćžšđČĆŽŠĐ\\\\\u{302}\u{301} .-]*"
+        ) {
+            let converter = ConverterBuilder::new().use_scheme(Scheme::SerbianReversible).build();
+
+            prop_assert_eq!(&text, &converter.lat_to_cyr(&converter.cyr_to_lat(&text)));
+        }
+    }
+
+    #[test]
+    fn it_converts_names_for_machine_readable_zone() {
+        let converter = ConverterBuilder::new().build();
+
+        assert_eq!("DJORDJE<DZAJIC", converter.to_icao("Ђорђе Џајић"));
+        assert_eq!("DJORDJE<DZAJIC", converter.to_icao("Đorđe Džajić"));
+        assert_eq!("CEDOMIR<STOSIC<SIMIC", converter.to_icao("Čedomir Stošić-Šimić"));
+        assert_eq!("ZIVKOVIC<LJUBICA", converter.to_icao("  Живковић  Љубица "));
+        assert_eq!("SHCHERBAKOV<MUELLER<OCONNOR", converter.to_icao("Щербаков Müller O'Connor"));
+        assert_eq!(
+            "PETROVIC<<NIKOLA<JOVAN<<<<<<<<<<<<<<",
+            converter.to_mrz_name("Петровић", "Никола Јован", 36)
+        );
+        assert_eq!("PETROVIC<<NIK", converter.to_mrz_name("Petrović", "Nikola", 13));
+
+        let russian = ConverterBuilder::new().use_scheme(Scheme::RussianIso9).build();
+
+        assert_eq!("ZHUKOV<IURII", russian.to_icao("Жуков Юрий"));
+
+        let reversible = ConverterBuilder::new().use_scheme(Scheme::SerbianReversible).build();
+
+        assert_eq!("LJUBICA<DZADZIC", reversible.to_icao("Љубица Џаџић"));
+        assert_eq!("DJORDJE<ZIVKOVIC", reversible.to_icao("Ђорђе Живковић"));
+
+        let macedonian = ConverterBuilder::new().use_scheme(Scheme::Macedonian).build();
+
+        assert_eq!("ZHAKLINA<SHOPOVA<DZEKOVA", macedonian.to_icao("Жаклина Шопова-Џекова"));
+    }
+
+    #[test]
+    fn it_restores_diacritics_of_known_words() {
+        let mut builder = ConverterBuilder::new();
+        builder.enable_diacritic_restoration();
+        let converter = builder.build();
+
+        assert_eq!(
+            "Ћевапчићи су често ЖУТИ, а Ђорђе Петровић живи у Нишу.",
+            converter.lat_to_cyr("Cevapcici su cesto ZUTI, a Djordje Petrovic zivi u Nisu.")
+        );
+
+        let report = converter.restore_diacritics("Ne znam sto, a kuca je na Zlatiboru.");
+
+        assert_eq!("Ne znam sto, a kuća je na Zlatiboru.", report.text);
+        assert_eq!(
+            vec![
+                (8..11, IssueKind::AmbiguousWord(vec!["što".to_string(), "sto".to_string()])),
+                (26..35, IssueKind::UnknownWord),
+            ],
+            report
+                .issues
+                .into_iter()
+                .map(|issue| (issue.range, issue.kind))
+                .collect::<Vec<_>>()
+        );
+
+        builder
+            .set_restoration_fallback(RestorationFallback::MostCommon)
+            .extend_restoration_words(["Zlatiboru"]);
+
+        let report = builder.build().lat_to_cyr_with_report("Sto je na Zlatiboru?");
+
+        assert_eq!("Што је на Златибору?", report.text);
+        assert_eq!(1, report.issues.len());
+    }
+
+    #[test]
+    fn it_does_not_restore_unknown_words_ending_in_ic() {
+        let mut builder = ConverterBuilder::new();
+        builder.enable_diacritic_restoration();
+        let converter = builder.build();
+
+        assert_eq!(
+            "Titanic i basic logic",
+            converter.restore_diacritics("Titanic i basic logic").text
+        );
+
+        let input = "feedback, stack, Black Jack, click i quick";
+        let report = converter.restore_diacritics(input);
+
+        assert_eq!(input, report.text);
+        assert_eq!(
+            vec!["feedback", "stack", "Black", "Jack", "click", "quick"],
+            report
+                .issues
+                .iter()
+                .map(|issue| issue.word.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            "Jovanović i Petrović",
+            converter.restore_diacritics("Jovanovic i Petrovic").text
+        );
+    }
+
+    #[test]
+    fn it_converts_english_digraphs_except_in_dictionary_words() {
+        let mut builder = ConverterBuilder::new();
+        builder.enable_english_digraphs();
+
+        assert_eq!(
+            "Шта радиш? ЧОВЕК је жив, ћао, ћерка. Исход је тјерати и летјети.",
+            builder
+                .build()
+                .lat_to_cyr("Shta radish? CHOVEK je zhiv, cjao, tjerka. Ishod je tjerati i letjeti.")
+        );
+
+        builder.extend_dictionary(["schema", "shop", "zhou"]);
+
+        assert_eq!(
+            "Сцхема, схоп и Зхоу.",
+            builder.build().lat_to_cyr("Schema, shop i Zhou.")
+        );
+        assert_eq!(
+            "Счема",
+            ConverterBuilder::new().enable_english_digraphs().build().lat_to_cyr("Schema")
+        );
+    }
+
+    #[test]
+    fn it_keeps_serbian_words_which_look_like_english_digraphs() {
+        let converter = ConverterBuilder::new().enable_english_digraphs().build();
+
+        assert_eq!(
+            "Тј. усхићен, несхватљив и обесхрабрен, али исхлађен.",
+            converter.lat_to_cyr("Tj. ushićen, neshvatljiv i obeshrabren, ali ishlađen.")
+        );
+        assert_eq!("тј. ћао", converter.lat_to_cyr("tj. tjao"));
+        assert_eq!(
+            "Тјерање и хтјење, облетјети, пролетјети и разлетјети.",
+            converter.lat_to_cyr("Tjeranje i htjenje, obletjeti, proletjeti i razletjeti.")
+        );
+    }
+
+    #[test]
+    fn it_applies_foreign_letter_policy() {
+        let input = "Wi-Fi u taxi vozilu, TAXI, Xerox, quiz, yoga i Sydney.";
+        let mut builder = ConverterBuilder::new();
+
+        assert_eq!(
+            "Wи-Фи у таxи возилу, ТАXИ, Xероx, qуиз, yога и Сyднеy.",
+            builder.build().lat_to_cyr(input)
+        );
+
+        builder.set_foreign_letter_policy(ForeignLetterPolicy::Transcribe);
+
+        assert_eq!(
+            "Ви-Фи у такси возилу, ТАКСИ, Ксерокс, квиз, јога и Сиднеј.",
+            builder.build().lat_to_cyr(input)
+        );
+
+        builder.set_foreign_letter_policy(ForeignLetterPolicy::SkipWord);
+
+        assert_eq!(
+            "Wi-Fi у taxi возилу, TAXI, Xerox, quiz, yoga и Sydney.",
+            builder.build().lat_to_cyr(input)
+        );
+
+        builder.set_foreign_letter_policy(ForeignLetterPolicy::Fail);
+        let converter = builder.build();
+        let error = converter.try_lat_to_cyr(input).unwrap_err();
+
+        assert_eq!(0..5, error.words[0].range);
+        assert_eq!(
+            "words with letters outside of the alphabet: Wi-Fi, taxi, TAXI, Xerox, quiz, yoga, Sydney",
+            error.to_string()
+        );
+        assert_eq!(Ok("Ћао".to_string()), converter.try_lat_to_cyr("Ćao"));
+    }
+
+    #[test]
+    fn it_transcribes_foreign_names() {
+        let mut builder = ConverterBuilder::new();
+        builder.enable_name_transcription();
+
+        assert_eq!(
+            "Шекспир, Гете и Вашингтон, а не бацх; ДИМА.",
+            builder.build().lat_to_cyr("Shakespeare, Goethe i Washington, a ne bach; DUMAS.")
+        );
+
+        let converter = ConverterBuilder::new().build();
+
+        assert_eq!("Шекспир", converter.transcribe_name("Shakespeare", SourceLanguage::English));
+        assert_eq!(
+            "Герхард Шредер",
+            converter.transcribe_name("Gerhard Schröder", SourceLanguage::German)
+        );
+        assert_eq!(
+            "Жан Анри Мартен",
+            converter.transcribe_name("Jean Henri Martin", SourceLanguage::French)
+        );
+        assert_eq!(
+            "Леонардо да Винчи",
+            converter.transcribe_name("Leonardo da Vinci", SourceLanguage::Italian)
+        );
+        assert_eq!("Том Ханкс", converter.transcribe_name("Tom Hanks", SourceLanguage::English));
+
+        builder
+            .extend_name_lexicon([("Goethe", "Гете"), ("Shakespeare", "Шекспијер")])
+            .add_foreign_names(SourceLanguage::German, ["Zuckerberg"]);
+
+        assert_eq!(
+            "Шекспијер и Цукерберг",
+            builder.build().lat_to_cyr("Shakespeare i Zuckerberg")
+        );
+    }
+
+    #[test]
+    fn it_keeps_detected_foreign_words_in_latin() {
+        let input = "Na iPhone-u pokreni JavaScript, OAuth2 i Wi-Fi, pa pročitaj Smith-ov README.";
+        let mut builder = ConverterBuilder::new();
+        builder.enable_foreign_word_detection().extend_foreign_acronyms(["README"]);
+        let report = builder.build().lat_to_cyr_with_report(input);
+
+        assert_eq!(
+            "На iPhone-u покрени JavaScript, OAuth2 и Wi-Fi, па прочитај Smith-ov README.",
+            report.text
+        );
+        assert_eq!(
+            vec![
+                ("iPhone-u", IssueKind::ForeignWord(ForeignWordRule::CamelCase)),
+                ("JavaScript", IssueKind::ForeignWord(ForeignWordRule::CamelCase)),
+                ("OAuth2", IssueKind::ForeignWord(ForeignWordRule::DigitsWithLetters)),
+                ("Wi-Fi", IssueKind::ForeignWord(ForeignWordRule::ForeignLetters)),
+                ("Smith-ov", IssueKind::ForeignWord(ForeignWordRule::ImpossibleSequences)),
+                ("README", IssueKind::ForeignWord(ForeignWordRule::Acronyms)),
+            ],
+            report
+                .issues
+                .iter()
+                .map(|issue| (&input[issue.range.clone()], issue.kind.clone()))
+                .collect::<Vec<_>>()
+        );
+
+        builder
+            .disable_foreign_word_rule(ForeignWordRule::CamelCase)
+            .disable_foreign_word_rule(ForeignWordRule::ImpossibleSequences)
+            .disable_foreign_word_rule(ForeignWordRule::Acronyms);
+
+        assert_eq!(
+            "ЈаваСцрипт, OAuth2 и Смитх, РЕАДМЕ",
+            builder.build().lat_to_cyr("JavaScript, OAuth2 i Smith, README")
+        );
+    }
+
+    #[test]
+    fn it_detects_uppercase_foreign_acronyms() {
+        let input = "Pogledaj FAQ i PHP-u dokumentaciju za SAD i NATO.";
+        let mut builder = ConverterBuilder::new();
+        builder.enable_foreign_word_rule(ForeignWordRule::Acronyms);
+        let report = builder.build().lat_to_cyr_with_report(input);
+
+        assert_eq!("Погледај FAQ и PHP-u документацију за САД и НАТО.", report.text);
+        assert_eq!(
+            vec!["FAQ", "PHP-u"],
+            report.issues.iter().map(|issue| &input[issue.range.clone()]).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn it_keeps_foreign_sentences_in_latin() {
+        let input = "Uputstvo je na engleskom. Please read the manual before you start. Hvala!";
+        let mut builder = ConverterBuilder::new();
+        builder.enable_language_identification();
+        let report = builder.build().lat_to_cyr_with_report(input);
+
+        assert_eq!(
+            "Упутство је на енглеском. Please read the manual before you start. Хвала!",
+            report.text
+        );
+        assert_eq!(1, report.issues.len());
+        assert_eq!("Please read the manual before you start.", report.issues[0].word);
+        assert_eq!(
+            IssueKind::ForeignSentence(Language::English),
+            report.issues[0].kind
+        );
+        assert_eq!(
+            Some(Language::Serbian),
+            identify_language("Sutra ce biti lepo vreme.").map(|guess| guess.language)
+        );
+
+        builder.set_language_confidence_threshold(1.0);
+
+        assert_eq!(
+            "Плеасе реад тхе мануал бефоре yоу старт.",
+            builder.build().lat_to_cyr("Please read the manual before you start.")
+        );
+    }
+
+    #[test]
+    fn it_converts_between_ekavian_and_ijekavian() {
+        let mut builder = ConverterBuilder::new();
+        let converter = builder.build();
+
+        assert_eq!(
+            "Dijete je vidjelo rijeku i pilo mlijeko.",
+            converter
+                .convert_dialect("Dete je videlo reku i pilo mleko.", Dialect::Ijekavian)
+                .text
+        );
+        assert_eq!(
+            "ДЕЦА ПЕВАЈУ ПЕСМУ.",
+            converter
+                .convert_dialect("ДЈЕЦА ПЈЕВАЈУ ПЈЕСМУ.", Dialect::Ekavian)
+                .text
+        );
+
+        let report =
+            converter.convert_dialect("Ceo svet zna da je pijesak sitan.", Dialect::Ijekavian);
+        assert_eq!("Cio svet zna da je pijesak sitan.", report.text);
+        assert_eq!(
+            vec![IssueKind::AmbiguousWord(vec!["svijet".to_string(), "svet".to_string()])],
+            report.issues.into_iter().map(|issue| issue.kind).collect::<Vec<_>>()
+        );
+
+        let report = converter.convert_dialect("Pijesak je u Srbije.", Dialect::Ekavian);
+        assert_eq!(
+            vec![("Pijesak", IssueKind::UnknownWord)],
+            report
+                .issues
+                .iter()
+                .map(|issue| (issue.word.as_str(), issue.kind.clone()))
+                .collect::<Vec<_>>()
+        );
+
+        builder.extend_dialect_words([("pesak", "pijesak")]);
+        assert_eq!(
+            "Pesak je u Srbije.",
+            builder.build().convert_dialect("Pijesak je u Srbije.", Dialect::Ekavian).text
+        );
+
+        builder.enable_dj_conversion();
+        assert_eq!(
+            "Дјеца и нађена овдје.",
+            builder
+                .build()
+                .convert_dialect("Деца и нађена овде.", Dialect::Ijekavian)
+                .text
+        );
+    }
+
+    #[test]
+    fn it_modernizes_historical_spelling() {
+        let input = "Ѳеодоръ пише сербскій рѣчникъ и любовну пѣсму о свѣту.";
+        let converter = ConverterBuilder::new().build();
+
+        assert_eq!(
+            "Теодор пише сербски речник и љубовну песму о свету.",
+            converter.modernize_spelling(input, Dialect::Ekavian).text
+        );
+        assert_eq!(
+            "Теодор пише сербски рјечник и љубовну пјесму о свијету.",
+            converter.modernize_spelling(input, Dialect::Ijekavian).text
+        );
+
+        let report = ConverterBuilder::new()
+            .enable_historical_spelling(Dialect::Ijekavian)
+            .build()
+            .cyr_to_lat_with_report("Нѣшто о мѣсту ѧ.");
+
+        assert_eq!("Nešto o mjestu ѧ.", report.text);
+        assert_eq!(
+            vec!["Нѣшто", "ѧ"],
+            report
+                .issues
+                .iter()
+                .filter(|issue| issue.kind == IssueKind::HistoricalSpelling)
+                .map(|issue| issue.word.as_str())
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            "Djeca i braća",
+            ConverterBuilder::new()
+                .enable_dj_conversion()
+                .enable_historical_spelling(Dialect::Ijekavian)
+                .build()
+                .cyr_to_lat("Дѣца и братя")
+        );
+
+        let report = ConverterBuilder::new()
+            .use_scheme(Scheme::Macedonian)
+            .build()
+            .modernize_spelling("Ѓаконот ѕида ќерамида.", Dialect::Ekavian);
+
+        assert_eq!("Ѓаконот ѕида ќерамида.", report.text);
+        assert!(report.issues.is_empty());
+    }
 }
@@ -0,0 +1,736 @@
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use cyrla::{
+    detect_script, ConverterBuilder, Dialect, DigraphCase, Direction, ForeignLetterPolicy,
+    RestorationFallback, Scheme, Script, SourceLanguage,
+};
+
+const USAGE: &str = "Usage: cyrla [OPTIONS] [FILE]...
+
+Converts text between latin and cyrillic script. Reads standard input if no FILE is given or
+FILE is `-`, and writes to standard output unless --in-place or --output is given.
+
+Options:
+  -t, --to SCRIPT              Target script: cyr, lat or auto (default: auto, which converts to
+                               the script opposite to the one in which most of the text is written)
+  -s, --scheme SCHEME          Alphabets to convert between: serbian, macedonian,
+                               montenegrin, russian-iso9, russian-gost, russian-bgn,
+                               ukrainian, bulgarian or serbian-reversible
+                               (default: serbian)
+      --bald-latin             Convert `dj` to `ђ` and `dz` to `џ`
+      --dj                     Convert `dj` to `ђ`
+      --dz                     Convert `dz` to `џ`
+      --english-digraphs       Convert `sh`, `ch`, `zh`, `cj` and `tj` to `ш`, `ч`, `ж` and `ћ`
+      --ijekavian              Include ijekavian words in the dictionary
+      --dialect DIALECT        Convert yat of the known words to ekavian or ijekavian (`mleko`
+                               to `mlijeko`)
+      --dialect-words FILE     Add words from FILE to the word list of --dialect, as
+                               `ekavian = ijekavian`
+      --historical-spelling YAT
+                               Modernise historical cyrillic (`ѣ`, `ъ`, `і`) before converting
+                               it to latin, with yat written as ekavian or ijekavian
+      --restore-diacritics     Restore diacritics of latin words written without them (`zivot`)
+      --restoration-fallback FALLBACK
+                               Words whose diacritics can be restored in several ways: keep or
+                               most-common (default: keep)
+      --restoration-words FILE Add words with diacritics from FILE (one per line) to the word list
+      --foreign-letters POLICY Letters outside of the alphabet (`q`, `w`, `x`, `y`): keep,
+                               transcribe, skip-word or fail, which doesn't convert the file
+                               and lists words with such letters (default: keep)
+      --transcribe-names       Transcribe foreign names (`Shakespeare` to `Шекспир`)
+      --names FILE             Add names from FILE to the lexicon of foreign names, as
+                               `Name = Транскрипција`, or just `Name` after `[en]`, `[de]`,
+                               `[fr]` or `[it]` to transcribe it by the rules of that language
+      --detect-foreign         Keep words which look foreign (`iPhone`, `OAuth2`) in latin
+      --acronyms FILE          Add uppercase words from FILE (one per line) which are kept in
+                               latin by --detect-foreign (`README`)
+      --skip-foreign-sentences Keep sentences in English, German, French or Italian in latin
+      --dictionary FILE        Add prefixes from FILE (one per line) to the dictionary
+      --ignore FILE            Don't convert latin words from FILE (one per line)
+      --digraph-case CASE      Case of digraph converted from single letter word: title or upper
+  -i, --in-place               Overwrite converted files
+  -b, --backup SUFFIX          Keep original of every overwritten file with SUFFIX appended
+  -o, --output FILE            Write output to FILE
+  -r, --recursive              Convert files in given directories recursively, skipping
+                               symbolic links to directories
+      --include GLOB           Convert only files matching GLOB when converting directories
+      --exclude GLOB           Skip files matching GLOB when converting directories
+  -h, --help                   Print this help
+  -V, --version                Print version
+
+Lines of the word list FILEs (--dictionary, --ignore, --names and others) are trimmed, and empty
+lines and comments, which are `#` alone or start with `# `, are skipped, so `#tag` is a word.";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Target {
+    Cyrillic,
+    Latin,
+    Auto,
+}
+
+#[derive(Debug, Default)]
+struct Options {
+    target: Option<Target>,
+    scheme: Option<Scheme>,
+    dj_conversion: bool,
+    dz_conversion: bool,
+    english_digraphs: bool,
+    ijekavian: bool,
+    dialect: Option<Dialect>,
+    dialect_words: Vec<PathBuf>,
+    historical_yat: Option<Dialect>,
+    diacritic_restoration: bool,
+    restoration_fallback: Option<RestorationFallback>,
+    restoration_words: Vec<PathBuf>,
+    foreign_letter_policy: Option<ForeignLetterPolicy>,
+    name_transcription: bool,
+    names: Vec<PathBuf>,
+    foreign_word_detection: bool,
+    acronyms: Vec<PathBuf>,
+    language_identification: bool,
+    dictionaries: Vec<PathBuf>,
+    ignored: Vec<PathBuf>,
+    digraph_case: Option<DigraphCase>,
+    in_place: bool,
+    backup_suffix: Option<String>,
+    output: Option<PathBuf>,
+    recursive: bool,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    files: Vec<PathBuf>,
+}
+
+enum Command {
+    Convert(Box<Options>),
+    Help,
+    Version,
+}
+
+fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut options = Options::default();
+    let mut args = args.into_iter();
+    let mut only_files = false;
+
+    while let Some(arg) = args.next() {
+        if only_files || arg == "-" || !arg.starts_with('-') {
+            options.files.push(PathBuf::from(arg));
+            continue;
+        }
+
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => {
+                (name.to_string(), Some(value.to_string()))
+            }
+            _ => (arg.clone(), None),
+        };
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("missing value for `{}`", name))
+        };
+
+        match name.as_str() {
+            "--" => only_files = true,
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            "-t" | "--to" => {
+                options.target = Some(match value()?.as_str() {
+                    "cyr" | "cyrillic" => Target::Cyrillic,
+                    "lat" | "latin" => Target::Latin,
+                    "auto" => Target::Auto,
+                    other => return Err(format!("unknown script `{}`", other)),
+                })
+            }
+            "-s" | "--scheme" => {
+                options.scheme = Some(match value()?.as_str() {
+                    "serbian" => Scheme::Serbian,
+                    "macedonian" => Scheme::Macedonian,
+                    "montenegrin" => Scheme::Montenegrin,
+                    "russian-iso9" => Scheme::RussianIso9,
+                    "russian-gost" => Scheme::RussianGost779B,
+                    "russian-bgn" => Scheme::RussianBgnPcgn,
+                    "ukrainian" => Scheme::Ukrainian,
+                    "bulgarian" => Scheme::Bulgarian,
+                    "serbian-reversible" => Scheme::SerbianReversible,
+                    other => return Err(format!("unknown scheme `{}`", other)),
+                })
+            }
+            "--bald-latin" => {
+                options.dj_conversion = true;
+                options.dz_conversion = true;
+            }
+            "--dj" => options.dj_conversion = true,
+            "--dz" => options.dz_conversion = true,
+            "--english-digraphs" => options.english_digraphs = true,
+            "--ijekavian" => options.ijekavian = true,
+            "--dialect" => {
+                options.dialect = Some(match value()?.as_str() {
+                    "ekavian" => Dialect::Ekavian,
+                    "ijekavian" => Dialect::Ijekavian,
+                    other => return Err(format!("unknown dialect `{}`", other)),
+                })
+            }
+            "--dialect-words" => options.dialect_words.push(PathBuf::from(value()?)),
+            "--historical-spelling" => {
+                options.historical_yat = Some(match value()?.as_str() {
+                    "ekavian" => Dialect::Ekavian,
+                    "ijekavian" => Dialect::Ijekavian,
+                    other => return Err(format!("unknown dialect `{}`", other)),
+                })
+            }
+            "--restore-diacritics" => options.diacritic_restoration = true,
+            "--restoration-fallback" => {
+                options.restoration_fallback = Some(match value()?.as_str() {
+                    "keep" => RestorationFallback::Keep,
+                    "most-common" => RestorationFallback::MostCommon,
+                    other => return Err(format!("unknown restoration fallback `{}`", other)),
+                })
+            }
+            "--restoration-words" => options.restoration_words.push(PathBuf::from(value()?)),
+            "--foreign-letters" => {
+                options.foreign_letter_policy = Some(match value()?.as_str() {
+                    "keep" => ForeignLetterPolicy::Keep,
+                    "transcribe" => ForeignLetterPolicy::Transcribe,
+                    "skip-word" => ForeignLetterPolicy::SkipWord,
+                    "fail" => ForeignLetterPolicy::Fail,
+                    other => return Err(format!("unknown foreign letter policy `{}`", other)),
+                })
+            }
+            "--transcribe-names" => options.name_transcription = true,
+            "--names" => options.names.push(PathBuf::from(value()?)),
+            "--detect-foreign" => options.foreign_word_detection = true,
+            "--acronyms" => options.acronyms.push(PathBuf::from(value()?)),
+            "--skip-foreign-sentences" => options.language_identification = true,
+            "--dictionary" => options.dictionaries.push(PathBuf::from(value()?)),
+            "--ignore" => options.ignored.push(PathBuf::from(value()?)),
+            "--digraph-case" => {
+                options.digraph_case = Some(match value()?.as_str() {
+                    "title" => DigraphCase::Title,
+                    "upper" => DigraphCase::Upper,
+                    other => return Err(format!("unknown digraph case `{}`", other)),
+                })
+            }
+            "-i" | "--in-place" => options.in_place = true,
+            "-b" | "--backup" => options.backup_suffix = Some(value()?),
+            "-o" | "--output" => options.output = Some(PathBuf::from(value()?)),
+            "-r" | "--recursive" => options.recursive = true,
+            "--include" => options.include.push(value()?),
+            "--exclude" => options.exclude.push(value()?),
+            _ => return Err(format!("unknown option `{}`", name)),
+        }
+    }
+
+    if options.in_place && options.output.is_some() {
+        return Err("`--in-place` can't be used together with `--output`".to_string());
+    }
+
+    if options.backup_suffix.is_some() && !options.in_place {
+        return Err("`--backup` can be used only together with `--in-place`".to_string());
+    }
+
+    Ok(Command::Convert(Box::new(options)))
+}
+
+/// Matches `text` against the glob `pattern` supporting `*`, `?` and `**` (which also matches
+/// `/`)
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    fn matches(pattern: &[char], text: &[char]) -> bool {
+        match pattern.first() {
+            None => text.is_empty(),
+            Some('*') if pattern.get(1) == Some(&'*') => {
+                let rest = pattern[2..].strip_prefix(&['/']).unwrap_or(&pattern[2..]);
+                (0..=text.len()).any(|i| matches(rest, &text[i..]))
+            }
+            Some('*') => (0..=text.len())
+                .take_while(|&i| i == 0 || text[i - 1] != '/')
+                .any(|i| matches(&pattern[1..], &text[i..])),
+            Some('?') => {
+                text.first().is_some_and(|&c| c != '/') && matches(&pattern[1..], &text[1..])
+            }
+            Some(&c) => text.first() == Some(&c) && matches(&pattern[1..], &text[1..]),
+        }
+    }
+
+    matches(&pattern, &text)
+}
+
+/// Matches the file against the glob, using only its name if the glob doesn't contain `/`
+fn matches_glob(glob: &str, relative_path: &Path) -> bool {
+    let path = relative_path.to_string_lossy().replace('\\', "/");
+
+    if glob.contains('/') {
+        glob_match(glob, &path)
+    } else {
+        glob_match(glob, path.rsplit('/').next().unwrap_or_default())
+    }
+}
+
+/// Reads trimmed lines of the file, skipping empty lines and comments (`#` alone or followed by a
+/// space), so words starting with `#` can be listed
+fn read_lines(path: &Path) -> io::Result<Vec<String>> {
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && *line != "#" && !line.starts_with("# "))
+        .map(String::from)
+        .collect())
+}
+
+/// Reads lines of all the files, see `read_lines`
+fn read_all_lines(paths: &[PathBuf]) -> Result<Vec<String>, String> {
+    Ok(paths
+        .iter()
+        .map(|path| read_lines(path).map_err(|error| format!("{}: {}", path.display(), error)))
+        .collect::<Result<Vec<_>, _>>()?
+        .concat())
+}
+
+/// Reads pairs of ekavian and ijekavian words from the files, see `--dialect-words`
+fn read_dialect_words(paths: &[PathBuf]) -> Result<Vec<(String, String)>, String> {
+    read_all_lines(paths)?
+        .into_iter()
+        .map(|line| match line.split_once('=') {
+            Some((ekavian, ijekavian)) => {
+                Ok((ekavian.trim().to_string(), ijekavian.trim().to_string()))
+            }
+            None => Err(format!("dialect word `{}` has no ijekavian form", line)),
+        })
+        .collect()
+}
+
+/// Adds names from the files to the lexicon of foreign names, see `--names`
+fn add_names(paths: &[PathBuf], builder: &mut ConverterBuilder) -> Result<(), String> {
+    for path in paths {
+        let mut language = None;
+
+        for line in read_lines(path).map_err(|error| format!("{}: {}", path.display(), error))? {
+            if let Some(tag) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                language = Some(match tag {
+                    "en" => SourceLanguage::English,
+                    "de" => SourceLanguage::German,
+                    "fr" => SourceLanguage::French,
+                    "it" => SourceLanguage::Italian,
+                    other => {
+                        return Err(format!("{}: unknown language `{}`", path.display(), other))
+                    }
+                });
+                continue;
+            }
+
+            match (line.split_once('='), language) {
+                (Some((name, transcription)), _) => {
+                    builder.extend_name_lexicon([(name.trim(), transcription.trim())]);
+                }
+                (None, Some(language)) => {
+                    builder.add_foreign_names(language, [line]);
+                }
+                (None, None) => {
+                    return Err(format!(
+                        "{}: name `{}` has neither transcription nor language",
+                        path.display(),
+                        line
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn collect_files(options: &Options, errors: &mut usize) -> Vec<PathBuf> {
+    let mut files = vec![];
+
+    for path in &options.files {
+        if !path.is_dir() {
+            files.push(path.clone());
+            continue;
+        }
+
+        if !options.recursive {
+            eprintln!(
+                "cyrla: {}: is a directory (use --recursive)",
+                path.display()
+            );
+            *errors += 1;
+            continue;
+        }
+
+        let mut directories = vec![path.clone()];
+
+        while let Some(directory) = directories.pop() {
+            let entries = match fs::read_dir(&directory) {
+                Ok(entries) => entries,
+                Err(error) => {
+                    eprintln!("cyrla: {}: {}", directory.display(), error);
+                    *errors += 1;
+                    continue;
+                }
+            };
+
+            let mut children: Vec<(PathBuf, fs::FileType)> = entries
+                .filter_map(|entry| {
+                    let entry = entry.ok()?;
+                    Some((entry.path(), entry.file_type().ok()?))
+                })
+                .collect();
+            children.sort_by(|(first, _), (second, _)| first.cmp(second));
+
+            for (child, file_type) in children.into_iter().rev() {
+                if file_type.is_dir() {
+                    directories.push(child);
+                    continue;
+                }
+
+                // Linked directories are skipped, since they can link back to their parents
+                if file_type.is_symlink() && child.is_dir() {
+                    continue;
+                }
+
+                let relative = child.strip_prefix(path).unwrap_or(&child);
+                let is_included = options.include.is_empty()
+                    || options
+                        .include
+                        .iter()
+                        .any(|glob| matches_glob(glob, relative));
+                let is_excluded = options
+                    .exclude
+                    .iter()
+                    .any(|glob| matches_glob(glob, relative));
+
+                if is_included && !is_excluded {
+                    files.push(child);
+                }
+            }
+        }
+    }
+
+    files
+}
+
+fn direction_for(target: Target, text: &str) -> Direction {
+    match target {
+        Target::Cyrillic => Direction::LatinToCyrillic,
+        Target::Latin => Direction::CyrillicToLatin,
+        Target::Auto => match detect_script(text).dominant_script() {
+            Some(Script::Cyrillic) => Direction::CyrillicToLatin,
+            _ => Direction::LatinToCyrillic,
+        },
+    }
+}
+
+/// Writes `content` to `path` through a temporary file, so the original is never left half
+/// written
+fn write_atomically(path: &Path, content: &str) -> io::Result<()> {
+    let mut temporary = path.as_os_str().to_owned();
+    temporary.push(".cyrla-tmp");
+    let temporary = PathBuf::from(temporary);
+
+    fs::write(&temporary, content)?;
+
+    if let Ok(metadata) = fs::metadata(path) {
+        fs::set_permissions(&temporary, metadata.permissions())?;
+    }
+
+    fs::rename(&temporary, path).inspect_err(|_| {
+        let _ = fs::remove_file(&temporary);
+    })
+}
+
+fn run(options: &Options) -> Result<usize, String> {
+    let dictionary = read_all_lines(&options.dictionaries)?;
+    let ignored = read_all_lines(&options.ignored)?;
+    let restoration_words = read_all_lines(&options.restoration_words)?;
+    let dialect_words = read_dialect_words(&options.dialect_words)?;
+    let acronyms = read_all_lines(&options.acronyms)?;
+
+    let mut builder = ConverterBuilder::new();
+    builder
+        .extend_dictionary(dictionary)
+        .add_ignored_latin_words(ignored)
+        .extend_restoration_words(restoration_words)
+        .extend_dialect_words(dialect_words)
+        .extend_foreign_acronyms(acronyms);
+
+    if options.dj_conversion {
+        builder.enable_dj_conversion();
+    }
+
+    if options.dz_conversion {
+        builder.enable_dz_conversion();
+    }
+
+    if options.english_digraphs {
+        builder.enable_english_digraphs();
+    }
+
+    if options.ijekavian {
+        builder.enable_ijekavian_inclusion();
+    }
+
+    if let Some(yat) = options.historical_yat {
+        builder.enable_historical_spelling(yat);
+    }
+
+    if options.diacritic_restoration {
+        builder.enable_diacritic_restoration();
+    }
+
+    if let Some(fallback) = options.restoration_fallback {
+        builder.set_restoration_fallback(fallback);
+    }
+
+    if let Some(policy) = options.foreign_letter_policy {
+        builder.set_foreign_letter_policy(policy);
+    }
+
+    if options.name_transcription {
+        builder.enable_name_transcription();
+    }
+
+    add_names(&options.names, &mut builder)?;
+
+    if options.foreign_word_detection {
+        builder.enable_foreign_word_detection();
+    }
+
+    if options.language_identification {
+        builder.enable_language_identification();
+    }
+
+    if let Some(scheme) = options.scheme {
+        builder.use_scheme(scheme);
+    }
+
+    if let Some(case) = options.digraph_case {
+        builder.set_single_letter_digraph_case(case);
+    }
+
+    let converter = builder.build();
+    let target = options.target.unwrap_or(Target::Auto);
+    let is_failing = options.foreign_letter_policy == Some(ForeignLetterPolicy::Fail);
+    // conversion of the dialect needs the whole text, as do detection of its script and checking
+    // it for foreign letters
+    let is_streamed = target != Target::Auto && options.dialect.is_none() && !is_failing;
+    let convert = |text: &str| -> io::Result<String> {
+        let converted = match direction_for(target, text) {
+            Direction::LatinToCyrillic if is_failing => converter
+                .try_lat_to_cyr(text)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?,
+            direction => converter.convert(text, direction),
+        };
+
+        Ok(match options.dialect {
+            Some(dialect) => converter.convert_dialect(&converted, dialect).text,
+            None => converted,
+        })
+    };
+    let mut errors = 0;
+    let files = if options.files.is_empty() {
+        vec![PathBuf::from("-")]
+    } else {
+        collect_files(options, &mut errors)
+    };
+
+    let mut output: Box<dyn Write> = match &options.output {
+        Some(path) => Box::new(BufWriter::new(
+            File::create(path).map_err(|error| format!("{}: {}", path.display(), error))?,
+        )),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    };
+
+    for file in files {
+        let result = if file == Path::new("-") {
+            if options.in_place {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "can't edit standard input in place",
+                ))
+            } else if !is_streamed {
+                let mut text = String::new();
+
+                io::stdin()
+                    .lock()
+                    .read_to_string(&mut text)
+                    .and_then(|_| output.write_all(convert(&text)?.as_bytes()))
+            } else {
+                let direction = direction_for(target, "");
+                converter.convert_reader(BufReader::new(io::stdin().lock()), &mut output, direction)
+            }
+        } else if options.in_place {
+            fs::read_to_string(&file).and_then(|text| {
+                let converted = convert(&text)?;
+
+                if converted == text {
+                    return Ok(());
+                }
+
+                if let Some(suffix) = &options.backup_suffix {
+                    let mut backup = file.as_os_str().to_owned();
+                    backup.push(suffix);
+                    fs::copy(&file, PathBuf::from(backup))?;
+                }
+
+                write_atomically(&file, &converted)
+            })
+        } else if !is_streamed {
+            fs::read_to_string(&file).and_then(|text| output.write_all(convert(&text)?.as_bytes()))
+        } else {
+            File::open(&file).and_then(|input| {
+                converter.convert_reader(
+                    BufReader::new(input),
+                    &mut output,
+                    direction_for(target, ""),
+                )
+            })
+        };
+
+        if let Err(error) = result {
+            eprintln!("cyrla: {}: {}", file.display(), error);
+            errors += 1;
+        }
+    }
+
+    output.flush().map_err(|error| error.to_string())?;
+
+    Ok(errors)
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Command::Convert(options)) => options,
+        Ok(Command::Help) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Ok(Command::Version) => {
+            println!("cyrla {}", env!("CARGO_PKG_VERSION"));
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("cyrla: {}\n\n{}", error, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&options) {
+        Ok(0) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("cyrla: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(args: &[&str]) -> Options {
+        match parse_args(args.iter().map(|arg| arg.to_string())) {
+            Ok(Command::Convert(options)) => *options,
+            _ => panic!("expected options"),
+        }
+    }
+
+    #[test]
+    fn it_parses_arguments() {
+        let options = options(&[
+            "--to=cyr",
+            "--bald-latin",
+            "--restore-diacritics",
+            "--restoration-fallback=most-common",
+            "--foreign-letters",
+            "transcribe",
+            "--transcribe-names",
+            "--dialect",
+            "ijekavian",
+            "--historical-spelling=ekavian",
+            "-i",
+            "-b",
+            ".orig",
+            "-r",
+            "--include",
+            "*.txt",
+            "docs",
+            "-",
+        ]);
+
+        assert_eq!(Some(Target::Cyrillic), options.target);
+        assert!(
+            options.dj_conversion && options.dz_conversion && options.in_place && options.recursive
+        );
+        assert!(options.diacritic_restoration);
+        assert_eq!(
+            Some(RestorationFallback::MostCommon),
+            options.restoration_fallback
+        );
+        assert_eq!(
+            Some(ForeignLetterPolicy::Transcribe),
+            options.foreign_letter_policy
+        );
+        assert_eq!(
+            Some(ForeignLetterPolicy::Fail),
+            self::options(&["--foreign-letters=fail"]).foreign_letter_policy
+        );
+        assert!(options.name_transcription);
+        assert_eq!(Some(Dialect::Ijekavian), options.dialect);
+        assert_eq!(Some(Dialect::Ekavian), options.historical_yat);
+        assert_eq!(Some(".orig".to_string()), options.backup_suffix);
+        assert_eq!(vec!["*.txt".to_string()], options.include);
+        assert_eq!(
+            vec![PathBuf::from("docs"), PathBuf::from("-")],
+            options.files
+        );
+        assert!(parse_args(vec!["--to".to_string(), "greek".to_string()]).is_err());
+        assert!(parse_args(vec!["--backup".to_string(), ".bak".to_string()]).is_err());
+    }
+
+    #[test]
+    fn it_matches_globs() {
+        assert!(matches_glob("*.md", Path::new("docs/readme.md")));
+        assert!(!matches_glob("*.md", Path::new("docs/readme.txt")));
+        assert!(matches_glob(
+            "docs/**/*.md",
+            Path::new("docs/a/b/readme.md")
+        ));
+        assert!(matches_glob("docs/**/*.md", Path::new("docs/readme.md")));
+        assert!(!matches_glob("docs/*.md", Path::new("docs/a/readme.md")));
+        assert!(matches_glob("file?.txt", Path::new("file1.txt")));
+    }
+
+    #[test]
+    fn it_skips_only_comment_lines_of_word_lists() {
+        let path = std::env::temp_dir().join(format!("cyrla-words-{}.txt", std::process::id()));
+        fs::write(&path, "# Ignored words\n#\n#tag\n  njiva  \n\n").unwrap();
+        let lines = read_lines(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(vec!["#tag", "njiva"], lines.unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn it_skips_linked_directories_when_converting_recursively() {
+        let root = std::env::temp_dir().join(format!("cyrla-tree-{}", std::process::id()));
+        fs::create_dir_all(root.join("docs")).unwrap();
+        fs::write(root.join("docs/a.txt"), "njiva").unwrap();
+        std::os::unix::fs::symlink(&root, root.join("docs/loop")).unwrap();
+        std::os::unix::fs::symlink(root.join("docs/a.txt"), root.join("b.txt")).unwrap();
+
+        let mut errors = 0;
+        let files = collect_files(&options(&["-r", root.to_str().unwrap()]), &mut errors);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(0, errors);
+        assert_eq!(vec![root.join("b.txt"), root.join("docs/a.txt")], files);
+    }
+}
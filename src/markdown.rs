@@ -0,0 +1,153 @@
+//! Conversion of Markdown documents which converts only the prose
+
+use std::ops::Range;
+
+use pulldown_cmark::{Event, LinkType, Options, Parser, Tag};
+
+use crate::html::decode_entity;
+use crate::{Converter, Direction};
+
+/// Converts Markdown documents, touching only the prose: paragraphs, headings, link text and
+/// image alt text. Code blocks, inline code, link destinations and titles, reference
+/// definitions, autolinks, HTML and front matter are kept as they are, and so is everything
+/// between the converted text (markup, whitespace and line endings). Character references of
+/// letters (`&scaron;`) are decoded and converted with the rest of the word, while other
+/// references are kept. The text of shortcut and collapsed reference links (`[njiva]`,
+/// `[njiva][]`) is kept too, since it's also the label of their definition.
+///
+/// Available with the `markdown` feature, which is enabled by default.
+///
+/// ```rust
+/// use cyrla::{ConverterBuilder, Direction, MarkdownConverter};
+///
+/// let converter = ConverterBuilder::new().build();
+/// let markdown = MarkdownConverter::new(&converter).convert(
+///     "# Naslov\n\nPogledaj [njivu](https://njiva.rs) i `kod`.\n",
+///     Direction::LatinToCyrillic,
+/// );
+///
+/// assert_eq!("# Наслов\n\nПогледај [њиву](https://njiva.rs) и `kod`.\n", markdown);
+/// ```
+pub struct MarkdownConverter<'c> {
+    converter: &'c Converter,
+}
+
+/// Returns `true` if the text of the link is also its label, so converting it would break the link
+/// (`[njiva]` and `[njiva][]` refer to `[njiva]: url`)
+fn is_label(link_type: LinkType) -> bool {
+    matches!(
+        link_type,
+        LinkType::Shortcut
+            | LinkType::ShortcutUnknown
+            | LinkType::Collapsed
+            | LinkType::CollapsedUnknown
+    )
+}
+
+fn is_skipped(tag: &Tag) -> bool {
+    match tag {
+        Tag::CodeBlock(_) | Tag::HtmlBlock | Tag::MetadataBlock(_) => true,
+        Tag::Link { link_type, .. } => {
+            matches!(link_type, LinkType::Autolink | LinkType::Email) || is_label(*link_type)
+        }
+        Tag::Image { link_type, .. } => is_label(*link_type),
+        _ => false,
+    }
+}
+
+/// Returns length of the character reference at the beginning of `text` (e.g. `&scaron;`)
+fn reference_len(text: &str) -> Option<usize> {
+    let end = text.find(';')?;
+
+    if end > 1 && text[1..end].chars().all(|c| c.is_ascii_alphanumeric() || c == '#') {
+        Some(end + 1)
+    } else {
+        None
+    }
+}
+
+impl<'c> MarkdownConverter<'c> {
+    pub fn new(converter: &'c Converter) -> MarkdownConverter<'c> {
+        MarkdownConverter { converter }
+    }
+
+    /// Converts the prose of the Markdown document in the given direction
+    pub fn convert(&self, markdown: &str, direction: Direction) -> String {
+        let options = Options::ENABLE_TABLES
+            | Options::ENABLE_FOOTNOTES
+            | Options::ENABLE_STRIKETHROUGH
+            | Options::ENABLE_TASKLISTS
+            | Options::ENABLE_HEADING_ATTRIBUTES
+            | Options::ENABLE_YAML_STYLE_METADATA_BLOCKS
+            | Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS;
+
+        let mut skipped: Vec<bool> = vec![];
+        let mut ranges: Vec<Range<usize>> = vec![];
+
+        for (event, range) in Parser::new_ext(markdown, options).into_offset_iter() {
+            match event {
+                Event::Start(tag) => {
+                    let is_parent_skipped = skipped.last().copied().unwrap_or(false);
+                    skipped.push(is_parent_skipped || is_skipped(&tag));
+                }
+                Event::End(_) => {
+                    skipped.pop();
+                }
+                Event::Text(_) if !skipped.last().copied().unwrap_or(false) => {
+                    // text of a single word can be split into several events
+                    match ranges.last_mut() {
+                        Some(last) if last.end == range.start => last.end = range.end,
+                        _ => ranges.push(range),
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut converted = String::with_capacity(markdown.len() * 2);
+        let mut copied = 0;
+
+        for range in ranges {
+            converted.push_str(&markdown[copied..range.start]);
+            self.convert_text(&markdown[range.clone()], direction, &mut converted);
+            copied = range.end;
+        }
+
+        converted.push_str(&markdown[copied..]);
+        converted
+    }
+
+    /// Converts source of the text, decoding character references of letters and keeping other
+    /// references as they are
+    fn convert_text(&self, text: &str, direction: Direction, converted: &mut String) {
+        let mut decoded = String::with_capacity(text.len());
+        let mut i = 0;
+
+        while let Some(position) = text[i..].find('&') {
+            decoded.push_str(&text[i..i + position]);
+            i += position;
+
+            if let Some((c, len)) = decode_entity(&text[i..]).filter(|(c, _)| c.is_alphabetic()) {
+                decoded.push(c);
+                i += len;
+                continue;
+            }
+
+            match reference_len(&text[i..]) {
+                Some(len) => {
+                    converted.push_str(&self.converter.convert(&decoded, direction));
+                    converted.push_str(&text[i..i + len]);
+                    decoded.clear();
+                    i += len;
+                }
+                None => {
+                    decoded.push('&');
+                    i += 1;
+                }
+            }
+        }
+
+        decoded.push_str(&text[i..]);
+        converted.push_str(&self.converter.convert(&decoded, direction));
+    }
+}
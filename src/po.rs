@@ -0,0 +1,489 @@
+//! Reading, transliterating and writing gettext PO files
+
+use std::error::Error;
+use std::fmt;
+
+use crate::{Converter, Direction, Scheme};
+
+/// Error returned when the PO file can't be parsed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoError {
+    /// Number of the line (starting from 1) on which the error occurred
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for PoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for PoError {}
+
+/// Translation (`msgstr` or one of `msgstr[n]`) of the entry
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Translation {
+    /// Index of the plural form, `None` for `msgstr` without index
+    pub plural_index: Option<usize>,
+    pub value: String,
+    /// Source lines of the translation, kept until the translation is changed
+    source: Option<String>,
+    /// Whether the value was written as `""` followed by the continuation lines
+    is_multiline: bool,
+    /// Whether the lines of the translation end with `\r\n`
+    is_crlf: bool,
+}
+
+/// Single entry of the PO file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoEntry {
+    /// Comment lines of the entry (translator, extracted, reference, flag and previous comments)
+    pub comments: Vec<String>,
+    pub msgctxt: Option<String>,
+    pub msgid: String,
+    pub msgid_plural: Option<String>,
+    pub translations: Vec<Translation>,
+    /// Source of everything before the first `msgstr`, written back as it is
+    source: String,
+}
+
+impl PoEntry {
+    /// Returns flags of the entry (e.g. `fuzzy` or `c-format`)
+    pub fn flags(&self) -> Vec<&str> {
+        self.comments
+            .iter()
+            .filter_map(|comment| comment.strip_prefix("#,"))
+            .flat_map(|flags| flags.split(','))
+            .map(str::trim)
+            .filter(|flag| !flag.is_empty())
+            .collect()
+    }
+
+    pub fn is_fuzzy(&self) -> bool {
+        self.flags().contains(&"fuzzy")
+    }
+
+    /// Returns `true` if this is the header entry of the file
+    pub fn is_header(&self) -> bool {
+        self.msgid.is_empty() && self.msgctxt.is_none()
+    }
+}
+
+/// Gettext PO file which can be transliterated and written back.
+///
+/// Everything except the translations is written back exactly as it was read, and so are the
+/// translations which were not changed.
+///
+/// ```rust
+/// use cyrla::po::PoFile;
+/// use cyrla::{ConverterBuilder, Direction};
+///
+/// let converter = ConverterBuilder::new().build();
+/// let mut po = PoFile::parse(concat!(
+///     "msgid \"\"\n",
+///     "msgstr \"Language: sr\\n\"\n",
+///     "\n",
+///     "#, python-format\n",
+///     "msgid \"Hello %(name)s\"\n",
+///     "msgstr \"Здраво %(name)s\"\n",
+/// ))
+/// .unwrap();
+///
+/// po.transliterate(&converter, Direction::CyrillicToLatin);
+///
+/// assert_eq!(
+///     concat!(
+///         "msgid \"\"\n",
+///         "msgstr \"Language: sr@latin\\n\"\n",
+///         "\n",
+///         "#, python-format\n",
+///         "msgid \"Hello %(name)s\"\n",
+///         "msgstr \"Zdravo %(name)s\"\n",
+///     ),
+///     po.to_string()
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoFile {
+    pub entries: Vec<PoEntry>,
+    /// Lines after the last entry
+    trailing: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Msgctxt,
+    Msgid,
+    MsgidPlural,
+    Msgstr,
+}
+
+fn decode_string(line: &str, line_number: usize) -> Result<String, PoError> {
+    let error = |message: &str| PoError {
+        line: line_number,
+        message: message.to_string(),
+    };
+
+    let content = line
+        .trim()
+        .strip_prefix('"')
+        .and_then(|line| line.strip_suffix('"'))
+        .ok_or_else(|| error("expected quoted string"))?;
+
+    let mut decoded = String::with_capacity(content.len());
+    let mut chars = content.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            decoded.push(c);
+            continue;
+        }
+
+        decoded.push(match chars.next() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('r') => '\r',
+            Some('a') => '\u{07}',
+            Some('b') => '\u{08}',
+            Some('f') => '\u{0C}',
+            Some('v') => '\u{0B}',
+            Some(c @ ('"' | '\\')) => c,
+            _ => return Err(error("invalid escape sequence")),
+        });
+    }
+
+    Ok(decoded)
+}
+
+fn encode_string(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len() + 2);
+    encoded.push('"');
+
+    for c in value.chars() {
+        match c {
+            '\n' => encoded.push_str("\\n"),
+            '\t' => encoded.push_str("\\t"),
+            '\r' => encoded.push_str("\\r"),
+            '"' => encoded.push_str("\\\""),
+            '\\' => encoded.push_str("\\\\"),
+            _ => encoded.push(c),
+        }
+    }
+
+    encoded.push('"');
+    encoded
+}
+
+/// Returns length of the printf, Python or brace format placeholder at the beginning of `text`
+fn placeholder_len(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+
+    match bytes.first()? {
+        b'{' => {
+            let end = text.find('}')?;
+            let (field, spec) = text[1..end].split_once(':').unwrap_or((&text[1..end], ""));
+
+            if field.chars().all(|c| c.is_ascii_alphanumeric() || "_.[]!".contains(c))
+                && spec.chars().all(|c| c.is_ascii_alphanumeric() || "_.<>^=+-#,% ".contains(c))
+            {
+                Some(end + 1)
+            } else {
+                None
+            }
+        }
+        b'%' => {
+            let mut i = 1;
+
+            if bytes.get(i) == Some(&b'(') {
+                i += text[i..].find(')')? + 1;
+            } else {
+                let digits = bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+
+                if digits > 0 && bytes.get(i + digits) == Some(&b'$') {
+                    i += digits + 1;
+                }
+            }
+
+            i += bytes[i..].iter().take_while(|b| b"-+ #0'".contains(b)).count();
+            i += bytes[i..].iter().take_while(|b| b.is_ascii_digit() || **b == b'*').count();
+
+            if bytes.get(i) == Some(&b'.') {
+                i += 1;
+                i += bytes[i..].iter().take_while(|b| b.is_ascii_digit() || **b == b'*').count();
+            }
+
+            i += bytes[i..].iter().take_while(|b| b"hlLqjzZt".contains(b)).count();
+
+            match bytes.get(i) {
+                Some(b) if b"diouxXeEfFgGaAcspnr%".contains(b) => Some(i + 1),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Converts the translation, keeping format placeholders as they are
+fn convert_translation(converter: &Converter, value: &str, direction: Direction) -> String {
+    let mut converted = String::with_capacity(value.len() * 2);
+    let mut copied = 0;
+    let mut i = 0;
+
+    while let Some(position) = value[i..].find(['%', '{']) {
+        i += position;
+
+        match placeholder_len(&value[i..]) {
+            Some(len) => {
+                converted.push_str(&converter.convert(&value[copied..i], direction));
+                converted.push_str(&value[i..i + len]);
+                i += len;
+                copied = i;
+            }
+            None => i += 1,
+        }
+    }
+
+    converted.push_str(&converter.convert(&value[copied..], direction));
+    converted
+}
+
+/// Rewrites `Language` header for the target script (`sr` to `sr@latin` and back)
+fn rewrite_language_header(header: &str, scheme: Scheme, direction: Direction) -> String {
+    header
+        .split_inclusive('\n')
+        .map(|line| {
+            let language = match line.strip_prefix("Language:") {
+                Some(language) => language.trim(),
+                None => return line.to_string(),
+            };
+            let (locale, modifier) = language.split_once('@').unwrap_or((language, ""));
+
+            if !scheme.is_language(locale) {
+                return line.to_string();
+            }
+
+            let rewritten = match (direction, modifier) {
+                (Direction::CyrillicToLatin, "" | "cyrillic") => format!("{}@latin", locale),
+                (Direction::LatinToCyrillic, "latin") => locale.to_string(),
+                _ => language.to_string(),
+            };
+            let ending = if line.ends_with('\n') { "\n" } else { "" };
+
+            format!("Language: {}{}", rewritten, ending)
+        })
+        .collect()
+}
+
+impl PoFile {
+    /// Parses PO file from its content
+    pub fn parse(input: &str) -> Result<PoFile, PoError> {
+        let mut entries = vec![];
+        let mut entry = PoEntry {
+            comments: vec![],
+            msgctxt: None,
+            msgid: String::new(),
+            msgid_plural: None,
+            translations: vec![],
+            source: String::new(),
+        };
+        let mut field: Option<Field> = None;
+        let mut has_msgid = false;
+
+        for (i, line) in input.split_inclusive('\n').enumerate() {
+            let line_number = i + 1;
+            let trimmed = line.trim();
+            let keyword = trimmed.split([' ', '\t', '"']).next().unwrap_or_default();
+
+            let starts_entry = match keyword {
+                _ if trimmed.is_empty() || trimmed.starts_with('#') => true,
+                "msgctxt" | "msgid" => true,
+                _ => false,
+            };
+
+            if starts_entry && !entry.translations.is_empty() {
+                entries.push(entry);
+                entry = PoEntry {
+                    comments: vec![],
+                    msgctxt: None,
+                    msgid: String::new(),
+                    msgid_plural: None,
+                    translations: vec![],
+                    source: String::new(),
+                };
+                has_msgid = false;
+            }
+
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                if trimmed.starts_with('#') {
+                    entry.comments.push(trimmed.to_string());
+                }
+
+                entry.source.push_str(line);
+                field = None;
+                continue;
+            }
+
+            if trimmed.starts_with('"') {
+                let value = decode_string(trimmed, line_number)?;
+
+                match field {
+                    Some(Field::Msgctxt) => {
+                        entry.msgctxt.get_or_insert_with(String::new).push_str(&value)
+                    }
+                    Some(Field::Msgid) => entry.msgid.push_str(&value),
+                    Some(Field::MsgidPlural) => {
+                        entry.msgid_plural.get_or_insert_with(String::new).push_str(&value)
+                    }
+                    Some(Field::Msgstr) => {
+                        let translation = entry.translations.last_mut().unwrap();
+                        translation.value.push_str(&value);
+                        translation.source.get_or_insert_with(String::new).push_str(line);
+                        translation.is_multiline = true;
+                        translation.is_crlf = line.ends_with("\r\n");
+                        continue;
+                    }
+                    None => {
+                        return Err(PoError {
+                            line: line_number,
+                            message: "string without keyword".to_string(),
+                        })
+                    }
+                }
+
+                entry.source.push_str(line);
+                continue;
+            }
+
+            let value = decode_string(&trimmed[keyword.len()..], line_number)?;
+
+            match keyword {
+                "msgctxt" => {
+                    entry.msgctxt = Some(value);
+                    field = Some(Field::Msgctxt);
+                }
+                "msgid" => {
+                    entry.msgid = value;
+                    has_msgid = true;
+                    field = Some(Field::Msgid);
+                }
+                "msgid_plural" => {
+                    entry.msgid_plural = Some(value);
+                    field = Some(Field::MsgidPlural);
+                }
+                _ if keyword == "msgstr" || keyword.starts_with("msgstr[") => {
+                    if !has_msgid {
+                        return Err(PoError {
+                            line: line_number,
+                            message: "msgstr without msgid".to_string(),
+                        });
+                    }
+
+                    let plural_index = match keyword.strip_prefix("msgstr[") {
+                        Some(index) => Some(
+                            index
+                                .strip_suffix(']')
+                                .and_then(|index| index.parse().ok())
+                                .ok_or_else(|| PoError {
+                                    line: line_number,
+                                    message: "invalid plural index".to_string(),
+                                })?,
+                        ),
+                        None => None,
+                    };
+
+                    entry.translations.push(Translation {
+                        plural_index,
+                        value,
+                        source: Some(line.to_string()),
+                        is_multiline: false,
+                        is_crlf: line.ends_with("\r\n"),
+                    });
+                    field = Some(Field::Msgstr);
+                    continue;
+                }
+                _ => {
+                    return Err(PoError {
+                        line: line_number,
+                        message: format!("unknown keyword `{}`", keyword),
+                    })
+                }
+            }
+
+            entry.source.push_str(line);
+        }
+
+        let trailing = if entry.translations.is_empty() {
+            if has_msgid {
+                return Err(PoError {
+                    line: input.lines().count(),
+                    message: "msgid without msgstr".to_string(),
+                });
+            }
+
+            entry.source
+        } else {
+            entries.push(entry);
+            String::new()
+        };
+
+        Ok(PoFile { entries, trailing })
+    }
+
+    /// Converts translations of all entries in the given direction, keeping printf
+    /// (`%s`, `%1$d`), Python (`%(name)s`) and brace (`{name}`) format placeholders as they are.
+    /// The `Language` header is updated to the target script (e.g. from `sr` to `sr@latin`).
+    pub fn transliterate(&mut self, converter: &Converter, direction: Direction) {
+        for entry in &mut self.entries {
+            let is_header = entry.is_header();
+
+            for translation in &mut entry.translations {
+                let value = if is_header {
+                    rewrite_language_header(&translation.value, converter.scheme(), direction)
+                } else {
+                    convert_translation(converter, &translation.value, direction)
+                };
+
+                if value != translation.value {
+                    translation.value = value;
+                    translation.source = None;
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for PoFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            f.write_str(&entry.source)?;
+
+            for translation in &entry.translations {
+                if let Some(source) = &translation.source {
+                    f.write_str(source)?;
+                    continue;
+                }
+
+                match translation.plural_index {
+                    Some(index) => write!(f, "msgstr[{}] ", index)?,
+                    None => f.write_str("msgstr ")?,
+                }
+
+                let value = &translation.value;
+                let ending = if translation.is_crlf { "\r\n" } else { "\n" };
+
+                if translation.is_multiline || value.trim_end_matches('\n').contains('\n') {
+                    write!(f, "\"\"{}", ending)?;
+
+                    for line in value.split_inclusive('\n') {
+                        write!(f, "{}{}", encode_string(line), ending)?;
+                    }
+                } else {
+                    write!(f, "{}{}", encode_string(value), ending)?;
+                }
+            }
+        }
+
+        f.write_str(&self.trailing)
+    }
+}
@@ -0,0 +1,68 @@
+//! Reports of words which the conversion couldn't handle with certainty
+
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+use crate::converter::ForeignWordRule;
+use crate::language::Language;
+
+/// Converted text together with the words which need attention
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConversionReport {
+    /// The converted text
+    pub text: String,
+    /// Words which need attention, in the order they appear in the input
+    pub issues: Vec<Issue>,
+}
+
+/// Word of the input which the conversion couldn't handle with certainty
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Issue {
+    /// The word (or the whole sentence) as it's written in the input
+    pub word: String,
+    /// Byte range of the word in the input
+    pub range: Range<usize>,
+    pub kind: IssueKind,
+}
+
+/// Reason why the word needs attention
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IssueKind {
+    /// Word may need diacritics (or another form of yat), but it isn't in the word list
+    UnknownWord,
+    /// Word is written in several ways with diacritics (`sto` as `što` or `sto`) or in the other
+    /// pronunciation of yat (`svet` as `svijet` or `svet`), listed from the most common one
+    AmbiguousWord(Vec<String>),
+    /// Word has letters which aren't in the alphabet of the scheme (`q`, `w`, `x` or `y`), so it
+    /// was left unconverted
+    ForeignLetters,
+    /// Word was recognised as foreign by the given rule of the foreign word detection, so it
+    /// was left unconverted
+    ForeignWord(ForeignWordRule),
+    /// Sentence was identified as written in the given foreign language, so it was left
+    /// unconverted
+    ForeignSentence(Language),
+    /// Word of historical text has old letters which couldn't be modernised, like `ѣ` in an
+    /// ijekavian word which isn't in the word list
+    HistoricalSpelling,
+}
+
+/// Error returned when the input has words with letters which aren't in the alphabet of the
+/// scheme and `ForeignLetterPolicy::Fail` is used
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForeignLettersError {
+    /// Words with foreign letters, in the order they appear in the input
+    pub words: Vec<Issue>,
+}
+
+impl fmt::Display for ForeignLettersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let words: Vec<&str> = self.words.iter().map(|issue| issue.word.as_str()).collect();
+
+        write!(f, "words with letters outside of the alphabet: {}", words.join(", "))
+    }
+}
+
+impl Error for ForeignLettersError {}
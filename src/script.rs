@@ -0,0 +1,158 @@
+//! Detection of the script in which the text is written
+
+use std::ops::Range;
+
+use crate::tokenizer::{tokenize, TokenKind};
+
+/// Script in which the text can be written
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Script {
+    Latin,
+    Cyrillic,
+}
+
+impl Script {
+    /// Returns script of the given letter or `None` if it's neither latin nor cyrillic letter
+    pub fn of(letter: char) -> Option<Script> {
+        match letter {
+            '\u{0400}'..='\u{052F}'
+            | '\u{1C80}'..='\u{1C8F}'
+            | '\u{2DE0}'..='\u{2DFF}'
+            | '\u{A640}'..='\u{A69F}' => Some(Script::Cyrillic),
+            '\u{00D7}' | '\u{00F7}' => None,
+            'A'..='Z' | 'a'..='z' | '\u{00C0}'..='\u{024F}' | '\u{1E00}'..='\u{1EFF}' => {
+                Some(Script::Latin)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Number of latin and cyrillic letters in a single word
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WordReport<'a> {
+    /// The word itself
+    pub word: &'a str,
+    /// Byte range of the word in the analysed text
+    pub range: Range<usize>,
+    pub latin_letters: usize,
+    pub cyrillic_letters: usize,
+}
+
+impl<'a> WordReport<'a> {
+    /// Returns script of the word, or `None` if the word is mixed or has no latin and no
+    /// cyrillic letters
+    pub fn script(&self) -> Option<Script> {
+        match (self.latin_letters, self.cyrillic_letters) {
+            (0, 0) => None,
+            (_, 0) => Some(Script::Latin),
+            (0, _) => Some(Script::Cyrillic),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the word contains both latin and cyrillic letters
+    pub fn is_mixed(&self) -> bool {
+        self.latin_letters > 0 && self.cyrillic_letters > 0
+    }
+}
+
+/// Result of the script detection returned by [`detect_script`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptReport<'a> {
+    pub latin_letters: usize,
+    pub cyrillic_letters: usize,
+    pub words: Vec<WordReport<'a>>,
+}
+
+fn ratio(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 / total as f64
+    }
+}
+
+impl<'a> ScriptReport<'a> {
+    /// Share of latin letters among latin and cyrillic letters of the text
+    pub fn latin_ratio(&self) -> f64 {
+        ratio(self.latin_letters, self.latin_letters + self.cyrillic_letters)
+    }
+
+    /// Share of cyrillic letters among latin and cyrillic letters of the text
+    pub fn cyrillic_ratio(&self) -> f64 {
+        ratio(self.cyrillic_letters, self.latin_letters + self.cyrillic_letters)
+    }
+
+    /// Share of words containing both latin and cyrillic letters among words containing any of
+    /// them
+    pub fn mixed_ratio(&self) -> f64 {
+        let words = self
+            .words
+            .iter()
+            .filter(|word| word.latin_letters + word.cyrillic_letters > 0)
+            .count();
+
+        ratio(self.words.iter().filter(|word| word.is_mixed()).count(), words)
+    }
+
+    /// Returns script of the text if all of its latin and cyrillic letters are written in it
+    pub fn script(&self) -> Option<Script> {
+        match (self.latin_letters, self.cyrillic_letters) {
+            (0, 0) => None,
+            (_, 0) => Some(Script::Latin),
+            (0, _) => Some(Script::Cyrillic),
+            _ => None,
+        }
+    }
+
+    /// Returns script in which the majority of the letters is written
+    pub fn dominant_script(&self) -> Option<Script> {
+        if self.latin_letters + self.cyrillic_letters == 0 {
+            None
+        } else if self.latin_letters > self.cyrillic_letters {
+            Some(Script::Latin)
+        } else {
+            Some(Script::Cyrillic)
+        }
+    }
+}
+
+/// Detects in which script the text is written, counting latin and cyrillic letters in the
+/// whole text and in every word
+pub fn detect_script(text: &str) -> ScriptReport<'_> {
+    let mut report = ScriptReport {
+        latin_letters: 0,
+        cyrillic_letters: 0,
+        words: vec![],
+    };
+
+    let words = tokenize(text)
+        .into_iter()
+        .filter(|token| token.kind == TokenKind::Word)
+        .map(|token| token.range);
+
+    for range in words {
+        let word = &text[range.clone()];
+        let mut word_report = WordReport {
+            word,
+            range,
+            latin_letters: 0,
+            cyrillic_letters: 0,
+        };
+
+        for c in word.chars() {
+            match Script::of(c) {
+                Some(Script::Latin) => word_report.latin_letters += 1,
+                Some(Script::Cyrillic) => word_report.cyrillic_letters += 1,
+                None => {}
+            }
+        }
+
+        report.latin_letters += word_report.latin_letters;
+        report.cyrillic_letters += word_report.cyrillic_letters;
+        report.words.push(word_report);
+    }
+
+    report
+}
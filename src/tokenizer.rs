@@ -0,0 +1,88 @@
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum TokenKind {
+    Word,
+    Separator,
+}
+
+/// Part of the text which is either a word or everything between two words
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Token {
+    pub(crate) kind: TokenKind,
+    pub(crate) range: Range<usize>,
+}
+
+fn is_word(segment: &str) -> bool {
+    segment.chars().any(char::is_alphanumeric)
+}
+
+fn is_hyphen(segment: &str) -> bool {
+    matches!(segment, "-" | "\u{2010}" | "\u{2011}")
+}
+
+/// Splits text into words and separators. The text is first split on Unicode word boundaries
+/// (UAX #29) and then the following segments are joined into a single word:
+///
+/// - words joined with a hyphen (`crno-beli`, `Wi-Fi`)
+/// - word followed by `#` or `+` signs (`C#`, `C++`) unless another word follows them (`a+b`)
+/// - dot at the beginning of the word which doesn't follow another word (`.NET`)
+///
+/// Concatenating all tokens gives back the original text.
+pub(crate) fn tokenize(text: &str) -> Vec<Token> {
+    let segments: Vec<(usize, &str)> = text.split_word_bound_indices().collect();
+    let mut tokens: Vec<Token> = vec![];
+    let mut i = 0;
+
+    let end_of = |index: usize| segments.get(index).map_or(text.len(), |&(start, _)| start);
+    let is_word_at = |index: usize| {
+        segments
+            .get(index)
+            .is_some_and(|&(_, segment)| is_word(segment))
+    };
+
+    while i < segments.len() {
+        let (start, segment) = segments[i];
+        let after_word = i > 0 && is_word(segments[i - 1].1);
+
+        if is_word(segment) || (segment == "." && !after_word && is_word_at(i + 1)) {
+            if !is_word(segment) {
+                i += 1;
+            }
+
+            i += 1;
+
+            while i + 1 < segments.len() && is_hyphen(segments[i].1) && is_word_at(i + 1) {
+                i += 2;
+            }
+
+            let mut symbols_end = i;
+
+            while symbols_end < segments.len() && matches!(segments[symbols_end].1, "#" | "+") {
+                symbols_end += 1;
+            }
+
+            if !is_word_at(symbols_end) {
+                i = symbols_end;
+            }
+
+            tokens.push(Token {
+                kind: TokenKind::Word,
+                range: start..end_of(i),
+            });
+        } else {
+            i += 1;
+
+            match tokens.last_mut() {
+                Some(token) if token.kind == TokenKind::Separator => token.range.end = end_of(i),
+                _ => tokens.push(Token {
+                    kind: TokenKind::Separator,
+                    range: start..end_of(i),
+                }),
+            }
+        }
+    }
+
+    tokens
+}